@@ -4,16 +4,27 @@ use std::io::Write;
 
 use structopt::StructOpt;
 
-static CATEGORIES: [varcon::Category; 4] = [
+static CATEGORIES: [varcon::Category; 5] = [
     varcon::Category::American,
     varcon::Category::BritishIse,
-    // For now, only want to support one form of British, so going with -ise as it seems more
-    // popular.
+    varcon::Category::BritishIze,
     varcon::Category::Canadian,
     varcon::Category::Australian,
     // Other basically means all
 ];
 
+/// From most to least accepted
+static TAGS: [varcon::Tag; 5] = [
+    varcon::Tag::Eq,
+    varcon::Tag::Variant,
+    varcon::Tag::Seldom,
+    varcon::Tag::Possible,
+    varcon::Tag::Improper,
+];
+
+/// Categories for each of `TAGS`
+type TaggedCategories = [varcon::CategorySet; 5];
+
 fn generate_variations<W: std::io::Write>(file: &mut W) {
     let entries = entries();
 
@@ -30,13 +41,29 @@ fn generate_variations<W: std::io::Write>(file: &mut W) {
     .unwrap();
     writeln!(file).unwrap();
 
-    writeln!(file, "pub type Variants = &'static [&'static str];",).unwrap();
+    writeln!(
+        file,
+        "pub type Variants = &'static [(&'static str, crate::Tag)];",
+    )
+    .unwrap();
     writeln!(
         file,
         "pub type VariantsMap = [Variants; {}];",
         CATEGORIES.len()
     )
     .unwrap();
+    writeln!(
+        file,
+        "/// Categories a word is listed under, for each `Tag` from most to least accepted"
+    )
+    .unwrap();
+    writeln!(file, "pub type CategoriesByTag = [u8; {}];", TAGS.len()).unwrap();
+    writeln!(file).unwrap();
+    writeln!(file, "pub const TAGS: [crate::Tag; {}] = [", TAGS.len()).unwrap();
+    for tag in TAGS.iter() {
+        writeln!(file, "    crate::Tag::{:?},", tag).unwrap();
+    }
+    writeln!(file, "];").unwrap();
     writeln!(file).unwrap();
 
     writeln!(file, "pub fn all_categories() -> crate::CategorySet {{",).unwrap();
@@ -56,7 +83,7 @@ fn generate_variations<W: std::io::Write>(file: &mut W) {
 
     writeln!(
         file,
-        "pub fn corrections(category: crate::Category, options: VariantsMap) -> &'static [&'static str] {{",
+        "pub fn corrections(category: crate::Category, options: VariantsMap) -> Variants {{",
     )
     .unwrap();
     writeln!(file, "  match category {{").unwrap();
@@ -70,7 +97,7 @@ fn generate_variations<W: std::io::Write>(file: &mut W) {
     }
     writeln!(
         file,
-        "    crate::Category::BritishIze | crate::Category::Other => unreachable!(\"{{:?}} is unused\", category),",
+        "    crate::Category::Other => unreachable!(\"{{:?}} is unused\", category),",
     )
     .unwrap();
     writeln!(file, "  }}").unwrap();
@@ -82,7 +109,7 @@ fn generate_variations<W: std::io::Write>(file: &mut W) {
     dictgen::generate_trie(
         file,
         "VARS",
-        "&[(u8, &VariantsMap)]",
+        "&[(CategoriesByTag, &VariantsMap)]",
         entry_sets.iter().flat_map(|kv| {
             let (word, data) = kv;
             if is_always_valid(data) {
@@ -115,11 +142,9 @@ fn generate_entry(file: &mut impl std::io::Write, symbol: &str, entry: &varcon_c
     writeln!(file, "pub(crate) static {}: VariantsMap = [", symbol).unwrap();
     for category in &CATEGORIES {
         let corrections = collect_correct(entry, *category);
-        let mut corrections: Vec<_> = corrections.iter().collect();
-        corrections.sort_unstable();
         writeln!(file, "  &[").unwrap();
-        for correction in &corrections {
-            writeln!(file, "    {:?},", correction).unwrap();
+        for (correction, tag) in &corrections {
+            writeln!(file, "    ({:?}, crate::Tag::{:?}),", correction, tag).unwrap();
         }
         writeln!(file, "  ],").unwrap();
     }
@@ -127,31 +152,41 @@ fn generate_entry(file: &mut impl std::io::Write, symbol: &str, entry: &varcon_c
     writeln!(file).unwrap();
 }
 
-fn generate_link(data: &[(&str, varcon::CategorySet)]) -> String {
+fn generate_link(data: &[(&str, TaggedCategories)]) -> String {
     let mut output = Vec::new();
 
     write!(output, "&[").unwrap();
-    for (symbol, set) in data.iter() {
-        write!(output, "(0b{:05b}, &{}), ", set.bits(), symbol).unwrap();
+    for (symbol, sets) in data.iter() {
+        write!(output, "([").unwrap();
+        let sets = sets.iter().map(|set| {
+            if set.is_empty() {
+                "0".to_owned()
+            } else {
+                format!("0b{:05b}", set.bits())
+            }
+        });
+        write!(output, "{}], &{}), ", itertools::join(sets, ", "), symbol).unwrap();
     }
     write!(output, "]").unwrap();
 
     String::from_utf8(output).unwrap()
 }
 
-fn is_always_valid(data: &[(&str, varcon::CategorySet)]) -> bool {
+fn is_always_valid(data: &[(&str, TaggedCategories)]) -> bool {
     let valid_categories = valid_categories();
-    for (_symbol, set) in data.iter() {
-        if *set == valid_categories {
+    for (_symbol, sets) in data.iter() {
+        // Even when strict
+        if sets[0] == valid_categories {
             return true;
         }
     }
     false
 }
 
-fn is_always_invalid(data: &[(&str, varcon::CategorySet)]) -> bool {
-    for (_symbol, set) in data.iter() {
-        if set.is_empty() {
+fn is_always_invalid(data: &[(&str, TaggedCategories)]) -> bool {
+    for (_symbol, sets) in data.iter() {
+        // Even when lenient
+        if sets.iter().all(|set| set.is_empty()) {
             return true;
         }
     }
@@ -190,7 +225,7 @@ fn entry_symbol(entry: &varcon_core::Entry) -> String {
 
 fn entry_sets<'e>(
     entries: impl Iterator<Item = (&'e String, &'e varcon_core::Entry)>,
-) -> BTreeMap<&'e str, Vec<(&'e str, varcon::CategorySet)>> {
+) -> BTreeMap<&'e str, Vec<(&'e str, TaggedCategories)>> {
     let mut sets = BTreeMap::new();
     for (symbol, entry) in entries {
         for (word, set) in entry_set(entry).iter() {
@@ -201,20 +236,27 @@ fn entry_sets<'e>(
     sets
 }
 
-fn entry_set(entry: &varcon_core::Entry) -> BTreeMap<&str, varcon::CategorySet> {
+fn entry_set(entry: &varcon_core::Entry) -> BTreeMap<&str, TaggedCategories> {
     let mut sets = BTreeMap::new();
     let valid_categories = valid_categories();
     for variant in entry.variants.iter() {
-        let set = sets
+        let sets = sets
             .entry(variant.word.as_str())
-            .or_insert_with(varcon::CategorySet::empty);
+            .or_insert_with(|| [varcon::CategorySet::empty(); 5]);
         for t in variant.types.iter() {
+            let tag = t.tag.unwrap_or(varcon::Tag::Eq);
+            let index = TAGS.iter().position(|t| *t == tag).unwrap();
             match t.category {
-                varcon::Category::Other => *set |= valid_categories,
-                varcon::Category::BritishIze => (),
-                _ => set.insert(t.category),
+                varcon::Category::Other => sets[index] |= valid_categories,
+                _ => sets[index].insert(t.category),
             }
         }
+        // Only keep the most accepted tag for each category
+        let mut seen = varcon::CategorySet::empty();
+        for set in sets.iter_mut() {
+            *set &= !seen;
+            seen |= *set;
+        }
     }
     sets
 }
@@ -227,10 +269,13 @@ fn valid_categories() -> varcon::CategorySet {
     c
 }
 
-fn collect_correct(entry: &varcon_core::Entry, category: varcon::Category) -> HashSet<&str> {
+fn collect_correct(
+    entry: &varcon_core::Entry,
+    category: varcon::Category,
+) -> BTreeMap<&str, varcon::Tag> {
     // If there is ambiguity, collect all potential options.
-    let mut primary = HashSet::new();
-    let mut backup = HashSet::new();
+    let mut primary = BTreeMap::new();
+    let mut backup: BTreeMap<&str, varcon::Tag> = BTreeMap::new();
     for variant in entry.variants.iter().filter(|v| !ignore_variant(v)) {
         for t in variant
             .types
@@ -239,10 +284,12 @@ fn collect_correct(entry: &varcon_core::Entry, category: varcon::Category) -> Ha
         {
             let tag = t.tag.unwrap_or(varcon::Tag::Eq);
             if tag == varcon::Tag::Eq {
-                primary.insert(variant.word.as_str());
+                primary.insert(variant.word.as_str(), tag);
             }
             if tag != varcon::Tag::Improper {
-                backup.insert(variant.word.as_str());
+                // Keep the most accepted form when a word is listed more than once
+                let best = backup.entry(variant.word.as_str()).or_insert(tag);
+                *best = (*best).min(tag);
             }
         }
     }
@@ -4,3 +4,14 @@ pub use crate::vars_codegen::*;
 
 pub use varcon_core::Category;
 pub use varcon_core::CategorySet;
+pub use varcon_core::Tag;
+
+/// Categories a word is valid for, accepting tags up to and including `max_tag`.
+pub fn valid_categories(categories: &CategoriesByTag, max_tag: Tag) -> CategorySet {
+    TAGS.iter()
+        .zip(categories.iter())
+        .filter(|(tag, _)| **tag <= max_tag)
+        .fold(CategorySet::empty(), |valid, (_, bits)| {
+            valid | CategorySet::from_bits_truncate(*bits)
+        })
+}
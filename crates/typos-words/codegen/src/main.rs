@@ -0,0 +1,84 @@
+use structopt::StructOpt;
+
+const ENGLISH: &str = include_str!("../../assets/frequency_dictionary_en_82_765.txt");
+const TYPOS: &[u8] = include_bytes!("../../../typos-dict/assets/words.csv");
+
+fn generate<W: std::io::Write>(file: &mut W) {
+    writeln!(
+        file,
+        "// This file is code-genned by {}",
+        env!("CARGO_PKG_NAME")
+    )
+    .unwrap();
+    writeln!(file).unwrap();
+
+    let typos: std::collections::HashSet<_> = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(TYPOS)
+        .records()
+        .map(|r| r.unwrap().iter().next().unwrap().to_lowercase())
+        .collect();
+    // The frequency list is missing some dialects' spellings, like "color", so fill them in from
+    // varcon
+    let varcon = varcon::VARCON
+        .iter()
+        .flat_map(|cluster| cluster.entries.iter())
+        .flat_map(|entry| entry.variants.iter())
+        .filter(|variant| {
+            variant
+                .types
+                .iter()
+                .any(|t| t.tag != Some(varcon::Tag::Improper))
+        })
+        .map(|variant| variant.word);
+    let valid: std::collections::BTreeSet<_> = parse_frequency_dict(ENGLISH)
+        .chain(varcon)
+        .map(|word| word.to_lowercase())
+        .filter(|word| 2 <= word.len() && word.bytes().all(|b| b.is_ascii_lowercase()))
+        .filter(|word| !typos.contains(word))
+        .collect();
+    writeln!(
+        file,
+        "/// Lowercase words known to be correctly spelled, sorted for binary search"
+    )
+    .unwrap();
+    writeln!(file, "pub static VALID_WORDS: &[&str] = &[").unwrap();
+    for word in valid {
+        writeln!(file, "    {:?},", word).unwrap();
+    }
+    writeln!(file, "];").unwrap();
+}
+
+fn parse_frequency_dict(raw: &str) -> impl Iterator<Item = &str> {
+    raw.trim_start_matches('\u{feff}')
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+}
+
+#[derive(Debug, StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+struct Options {
+    #[structopt(flatten)]
+    codegen: codegenrs::CodeGenArgs,
+    #[structopt(flatten)]
+    rustmft: codegenrs::RustfmtArgs,
+}
+
+fn run() -> Result<i32, Box<dyn std::error::Error>> {
+    let options = Options::from_args();
+
+    let mut content = vec![];
+    generate(&mut content);
+
+    let content = String::from_utf8(content)?;
+    let content = options.rustmft.reformat(&content)?;
+    options.codegen.write_str(&content)?;
+
+    Ok(0)
+}
+
+fn main() {
+    let code = run().unwrap();
+    std::process::exit(code);
+}
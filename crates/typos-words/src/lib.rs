@@ -0,0 +1,3 @@
+mod words_codegen;
+
+pub use crate::words_codegen::*;
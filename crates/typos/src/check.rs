@@ -7,9 +7,10 @@ pub fn check_str<'b, 's: 'b>(
     tokenizer: &'s tokens::Tokenizer,
     dictionary: &'s dyn Dictionary,
 ) -> impl Iterator<Item = Typo<'b>> {
+    let segment = tokenizer.segment_words();
     tokenizer
         .parse_str(buffer)
-        .flat_map(move |ident| process_ident(ident, dictionary))
+        .flat_map(move |ident| process_ident(ident, dictionary, segment))
 }
 
 pub fn check_bytes<'b, 's: 'b>(
@@ -17,29 +18,112 @@ pub fn check_bytes<'b, 's: 'b>(
     tokenizer: &'s tokens::Tokenizer,
     dictionary: &'s dyn Dictionary,
 ) -> impl Iterator<Item = Typo<'b>> {
+    let segment = tokenizer.segment_words();
     tokenizer
         .parse_bytes(buffer)
-        .flat_map(move |ident| process_ident(ident, dictionary))
+        .flat_map(move |ident| process_ident(ident, dictionary, segment))
+}
+
+/// Find words repeated across whitespace, like "the the".
+///
+/// Each run of repeated words is reported as one typo with the duplicates removed.
+pub fn check_doubled_str<'b, 's: 'b>(
+    buffer: &'b str,
+    tokenizer: &'s tokens::Tokenizer,
+) -> impl Iterator<Item = Typo<'b>> {
+    let words = tokenizer.parse_str(buffer).map(whole_word);
+    doubled_words(buffer.as_bytes(), words)
+}
+
+/// Find words repeated across whitespace, like "the the".
+///
+/// Each run of repeated words is reported as one typo with the duplicates removed.
+pub fn check_doubled_bytes<'b, 's: 'b>(
+    buffer: &'b [u8],
+    tokenizer: &'s tokens::Tokenizer,
+) -> impl Iterator<Item = Typo<'b>> {
+    let words = tokenizer.parse_bytes(buffer).map(whole_word);
+    doubled_words(buffer, words)
+}
+
+fn doubled_words<'b>(
+    buffer: &'b [u8],
+    words: impl Iterator<Item = Option<tokens::Word<'b>>>,
+) -> impl Iterator<Item = Typo<'b>> {
+    let mut words = words.peekable();
+    std::iter::from_fn(move || {
+        while let Some(first) = words.next() {
+            let first = match first {
+                Some(first) => first,
+                None => continue,
+            };
+            let mut last = first;
+            while let Some(Some(next)) = words.peek().copied() {
+                if !is_doubled(buffer, last, next) {
+                    break;
+                }
+                last = next;
+                words.next();
+            }
+            if last != first {
+                let end = last.offset() + last.token().len();
+                let typo = std::str::from_utf8(&buffer[first.offset()..end])
+                    .expect("words and whitespace are valid utf-8");
+                return Some(Typo {
+                    byte_offset: first.offset(),
+                    typo: typo.into(),
+                    corrections: crate::Status::Corrections(vec![first.token().into()]),
+                    details: Vec::new(),
+                });
+            }
+        }
+        None
+    })
+}
+
+/// Only identifiers that are a single word can be doubled, so `foo_bar bar` is left alone.
+fn whole_word(ident: tokens::Identifier<'_>) -> Option<tokens::Word<'_>> {
+    let mut words = ident.split();
+    match (words.next(), words.next()) {
+        (Some(word), None) if word.token().len() == ident.token().len() => Some(word),
+        _ => None,
+    }
+}
+
+fn is_doubled(buffer: &[u8], first: tokens::Word<'_>, second: tokens::Word<'_>) -> bool {
+    let gap = &buffer[first.offset() + first.token().len()..second.offset()];
+    let is_whitespace = std::str::from_utf8(gap)
+        .map(|gap| !gap.is_empty() && gap.chars().all(char::is_whitespace))
+        .unwrap_or(false);
+    is_whitespace
+        && first.token().chars().any(char::is_alphabetic)
+        && first
+            .token()
+            .chars()
+            .flat_map(char::to_lowercase)
+            .eq(second.token().chars().flat_map(char::to_lowercase))
 }
 
 fn process_ident<'i, 's: 'i>(
     ident: tokens::Identifier<'i>,
     dictionary: &'s dyn Dictionary,
+    segment: bool,
 ) -> impl Iterator<Item = Typo<'i>> {
-    match dictionary.correct_ident(ident) {
-        Some(crate::Status::Valid) => itertools::Either::Left(None.into_iter()),
-        Some(corrections) => {
+    match dictionary.correct_ident_with_details(ident) {
+        Some((crate::Status::Valid, _)) => itertools::Either::Left(None.into_iter()),
+        Some((corrections, details)) => {
             let typo = Typo {
                 byte_offset: ident.offset(),
                 typo: ident.token().into(),
                 corrections,
+                details,
             };
             itertools::Either::Left(Some(typo).into_iter())
         }
         None => itertools::Either::Right(
             ident
                 .split()
-                .filter_map(move |word| process_word(word, dictionary)),
+                .flat_map(move |word| process_word(word, dictionary, segment)),
         ),
     }
 }
@@ -47,27 +131,130 @@ fn process_ident<'i, 's: 'i>(
 fn process_word<'w, 's: 'w>(
     word: tokens::Word<'w>,
     dictionary: &'s dyn Dictionary,
-) -> Option<Typo<'w>> {
-    match dictionary.correct_word(word) {
-        Some(crate::Status::Valid) => None,
-        Some(corrections) => {
+    segment: bool,
+) -> impl Iterator<Item = Typo<'w>> {
+    match dictionary.correct_word_with_details(word) {
+        Some((crate::Status::Valid, _)) => itertools::Either::Left(None.into_iter()),
+        Some((corrections, details)) => {
             let typo = Typo {
                 byte_offset: word.offset(),
                 typo: word.token().into(),
                 corrections,
+                details,
             };
-            Some(typo)
+            itertools::Either::Left(Some(typo).into_iter())
         }
-        None => None,
+        None if segment => itertools::Either::Right(segment_word(word, dictionary).into_iter()),
+        None => itertools::Either::Left(None.into_iter()),
     }
 }
 
+const MIN_SEGMENT_LEN: usize = 2;
+// Shorter typos, like `ect`, are too likely to be the end of a valid word, like `dialect`
+const MIN_TYPO_SEGMENT_LEN: usize = 4;
+const MAX_SEGMENT_LEN: usize = 24;
+const MAX_SEGMENTED_LEN: usize = 64;
+
+/// Split a lowercase word into valid words and known typos, like `recieve` + `data`.
+///
+/// Prefers the fewest pieces and then the fewest typos, reporting nothing if the word is made up
+/// entirely of valid words.
+fn segment_word<'w, 's: 'w>(
+    word: tokens::Word<'w>,
+    dictionary: &'s dyn Dictionary,
+) -> Vec<Typo<'w>> {
+    let token = word.token();
+    if word.case() != tokens::Case::Lower
+        || !token.is_ascii()
+        || !(MIN_SEGMENT_LEN + MIN_TYPO_SEGMENT_LEN..=MAX_SEGMENTED_LEN).contains(&token.len())
+        || dictionary.is_valid_word(word)
+    {
+        return Vec::new();
+    }
+
+    struct Segment<'s> {
+        /// `(pieces, typos)`, to be minimized
+        cost: (usize, usize),
+        start: usize,
+        status: crate::Status<'s>,
+        details: Vec<crate::CorrectionDetails<'s>>,
+    }
+
+    let mut best: Vec<Option<Segment<'s>>> = Vec::with_capacity(token.len() + 1);
+    best.push(Some(Segment {
+        cost: (0, 0),
+        start: 0,
+        status: crate::Status::Valid,
+        details: Vec::new(),
+    }));
+    for end in 1..=token.len() {
+        let mut current: Option<Segment<'s>> = None;
+        for start in end.saturating_sub(MAX_SEGMENT_LEN)..=end.saturating_sub(MIN_SEGMENT_LEN) {
+            let prior = match &best[start] {
+                Some(prior) => prior.cost,
+                None => continue,
+            };
+            let piece = tokens::Word::new_unchecked(
+                &token[start..end],
+                tokens::Case::Lower,
+                word.offset() + start,
+            );
+            let (status, details) = match dictionary.correct_word_with_details(piece) {
+                Some((crate::Status::Valid, _)) => (crate::Status::Valid, Vec::new()),
+                Some(_) if end - start < MIN_TYPO_SEGMENT_LEN => continue,
+                Some(found) => found,
+                None if dictionary.is_valid_word(piece) => (crate::Status::Valid, Vec::new()),
+                None => continue,
+            };
+            let typos = if status == crate::Status::Valid { 0 } else { 1 };
+            let cost = (prior.0 + 1, prior.1 + typos);
+            if current.as_ref().map(|c| cost < c.cost).unwrap_or(true) {
+                current = Some(Segment {
+                    cost,
+                    start,
+                    status,
+                    details,
+                });
+            }
+        }
+        best.push(current);
+    }
+
+    let mut typos = Vec::new();
+    let mut end = token.len();
+    while 0 < end {
+        let segment = match best[end].take() {
+            Some(segment) => segment,
+            None => return Vec::new(),
+        };
+        if segment.status != crate::Status::Valid {
+            let piece = tokens::Word::new_unchecked(
+                &token[segment.start..end],
+                tokens::Case::Lower,
+                word.offset() + segment.start,
+            );
+            typos.push(Typo {
+                byte_offset: piece.offset(),
+                typo: piece.token().into(),
+                corrections: segment.status,
+                details: segment.details,
+            });
+        }
+        end = segment.start;
+    }
+    typos.reverse();
+    typos
+}
+
 /// An invalid term found in the buffer.
 #[derive(Clone, Debug)]
+#[non_exhaustive]
 pub struct Typo<'m> {
     pub byte_offset: usize,
     pub typo: Cow<'m, str>,
     pub corrections: crate::Status<'m>,
+    /// Details for each of the corrections, in the same order, if known.
+    pub details: Vec<crate::CorrectionDetails<'m>>,
 }
 
 impl<'m> Typo<'m> {
@@ -76,6 +263,7 @@ impl<'m> Typo<'m> {
             byte_offset: self.byte_offset,
             typo: Cow::Owned(self.typo.into_owned()),
             corrections: self.corrections.into_owned(),
+            details: self.details.into_iter().map(|d| d.into_owned()).collect(),
         }
     }
 
@@ -84,6 +272,7 @@ impl<'m> Typo<'m> {
             byte_offset: self.byte_offset,
             typo: Cow::Borrowed(self.typo.as_ref()),
             corrections: self.corrections.borrow(),
+            details: self.details.iter().map(|d| d.borrow()).collect(),
         }
     }
 }
@@ -94,6 +283,135 @@ impl<'m> Default for Typo<'m> {
             byte_offset: 0,
             typo: "".into(),
             corrections: crate::Status::Invalid,
+            details: Vec::new(),
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Status;
+
+    fn doubled(input: &str) -> Vec<(usize, String, String)> {
+        let tokenizer = tokens::Tokenizer::new();
+        check_doubled_str(input, &tokenizer)
+            .map(|typo| match typo.corrections {
+                crate::Status::Corrections(c) => {
+                    (typo.byte_offset, typo.typo.into_owned(), c[0].to_string())
+                }
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    struct Segments;
+
+    impl Dictionary for Segments {
+        fn correct_ident<'s, 'w>(&'s self, _ident: tokens::Identifier<'w>) -> Option<Status<'s>> {
+            None
+        }
+
+        fn correct_word<'s, 'w>(&'s self, word: tokens::Word<'w>) -> Option<Status<'s>> {
+            let correction = match word.token() {
+                "recieve" => "receive",
+                "lenght" => "length",
+                "teh" => "the",
+                _ => return None,
+            };
+            Some(Status::Corrections(vec![correction.into()]))
+        }
+
+        fn is_valid_word(&self, word: tokens::Word<'_>) -> bool {
+            matches!(word.token(), "get" | "data" | "getdata" | "the" | "an")
+        }
+    }
+
+    fn segmented(input: &str) -> Vec<(usize, String)> {
+        let tokenizer = tokens::TokenizerBuilder::new().segment_words(true).build();
+        check_str(input, &tokenizer, &Segments)
+            .map(|typo| (typo.byte_offset, typo.typo.into_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn segment_typos() {
+        assert_eq!(
+            segmented("recievedata getlenght getrecievedata"),
+            vec![
+                (0, "recieve".to_owned()),
+                (15, "lenght".to_owned()),
+                (25, "recieve".to_owned())
+            ]
+        );
+    }
+
+    #[test]
+    fn segment_valid() {
+        assert_eq!(segmented("getdata thedata andata"), vec![]);
+    }
+
+    #[test]
+    fn segment_unknown() {
+        assert_eq!(segmented("recievefoo foolenght"), vec![]);
+    }
+
+    #[test]
+    fn segment_short_typo() {
+        assert_eq!(segmented("tehdata"), vec![]);
+    }
+
+    #[test]
+    fn segment_only_lowercase() {
+        assert_eq!(segmented("RecieveData RECIEVEDATA"), vec![]);
+    }
+
+    #[test]
+    fn segment_disabled() {
+        let tokenizer = tokens::Tokenizer::new();
+        let typos: Vec<_> = check_str("recievedata", &tokenizer, &Segments).collect();
+        assert!(typos.is_empty());
+    }
+
+    #[test]
+    fn doubled_none() {
+        assert_eq!(doubled("the quick brown fox"), vec![]);
+    }
+
+    #[test]
+    fn doubled_space() {
+        assert_eq!(
+            doubled("Hello the the world"),
+            vec![(6, "the the".to_owned(), "the".to_owned())]
+        );
+    }
+
+    #[test]
+    fn doubled_newline() {
+        assert_eq!(
+            doubled("// it is\n// is fine\nand and\r\nmore"),
+            vec![(20, "and and".to_owned(), "and".to_owned())]
+        );
+    }
+
+    #[test]
+    fn doubled_case() {
+        assert_eq!(
+            doubled("The the end"),
+            vec![(0, "The the".to_owned(), "The".to_owned())]
+        );
+    }
+
+    #[test]
+    fn doubled_run() {
+        assert_eq!(
+            doubled("a a a b"),
+            vec![(0, "a a a".to_owned(), "a".to_owned())]
+        );
+    }
+
+    #[test]
+    fn doubled_requires_whitespace() {
+        assert_eq!(doubled("foo_foo foo.foo fooFoo foo foo_bar"), vec![]);
+    }
+}
@@ -7,20 +7,51 @@ pub trait Dictionary: Send + Sync {
     /// `None` if the status is unknown.
     fn correct_ident<'s, 'w>(&'s self, ident: crate::tokens::Identifier<'w>) -> Option<Status<'s>>;
 
+    /// Look up the validity of an Identifier, with details for each correction, in the same
+    /// order.
+    ///
+    /// `None` if the status is unknown.  The details are empty if unknown.
+    fn correct_ident_with_details<'s, 'w>(
+        &'s self,
+        ident: crate::tokens::Identifier<'w>,
+    ) -> Option<(Status<'s>, Vec<CorrectionDetails<'s>>)> {
+        self.correct_ident(ident).map(|status| (status, Vec::new()))
+    }
+
     /// Look up the validity of a Word.
     ///
     /// `None` if the status is unknown.
     fn correct_word<'s, 'w>(&'s self, word: crate::tokens::Word<'w>) -> Option<Status<'s>>;
+
+    /// Look up the validity of a Word, with details for each correction, in the same order.
+    ///
+    /// `None` if the status is unknown.  The details are empty if unknown.
+    fn correct_word_with_details<'s, 'w>(
+        &'s self,
+        word: crate::tokens::Word<'w>,
+    ) -> Option<(Status<'s>, Vec<CorrectionDetails<'s>>)> {
+        self.correct_word(word).map(|status| (status, Vec::new()))
+    }
+
+    /// Look up whether a Word is known to be spelled correctly.
+    ///
+    /// Used to segment Words without boundaries, like `recievedata`.  `false` if unknown.
+    fn is_valid_word(&self, _word: crate::tokens::Word<'_>) -> bool {
+        false
+    }
 }
 
 /// Validity of a term in a Dictionary.
 #[derive(Clone, PartialEq, Eq, Debug, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
 #[serde(untagged)]
+#[non_exhaustive]
 pub enum Status<'c> {
     Valid,
     Invalid,
     Corrections(Vec<Cow<'c, str>>),
+    /// Not a known word, with the closest known words, best first.
+    Suggestions(Vec<Suggestion<'c>>),
 }
 
 impl<'c> Status<'c> {
@@ -33,11 +64,18 @@ impl<'c> Status<'c> {
     pub fn is_correction(&self) -> bool {
         matches!(self, Status::Corrections(_))
     }
+    pub fn is_suggestion(&self) -> bool {
+        matches!(self, Status::Suggestions(_))
+    }
 
+    /// Replacement words, including suggestions.
     pub fn corrections_mut(&mut self) -> impl Iterator<Item = &mut Cow<'c, str>> {
         match self {
             Status::Corrections(corrections) => itertools::Either::Left(corrections.iter_mut()),
-            _ => itertools::Either::Right([].iter_mut()),
+            Status::Suggestions(suggestions) => itertools::Either::Right(itertools::Either::Left(
+                suggestions.iter_mut().map(|s| &mut s.word),
+            )),
+            _ => itertools::Either::Right(itertools::Either::Right([].iter_mut())),
         }
     }
 
@@ -52,6 +90,15 @@ impl<'c> Status<'c> {
                     .collect();
                 Status::Corrections(corrections)
             }
+            Status::Suggestions(suggestions) => Status::Suggestions(
+                suggestions
+                    .into_iter()
+                    .map(|s| Suggestion {
+                        word: Cow::Owned(s.word.into_owned()),
+                        distance: s.distance,
+                    })
+                    .collect(),
+            ),
         }
     }
 
@@ -64,7 +111,55 @@ impl<'c> Status<'c> {
                     .collect();
                 Status::Corrections(corrections)
             }
+            Status::Suggestions(suggestions) => Status::Suggestions(
+                suggestions
+                    .iter()
+                    .map(|s| Suggestion {
+                        word: Cow::Borrowed(s.word.as_ref()),
+                        distance: s.distance,
+                    })
+                    .collect(),
+            ),
             _ => self.clone(),
         }
     }
 }
+
+/// Where a correction came from and how much to trust it.
+#[derive(Clone, PartialEq, Debug, serde::Serialize)]
+pub struct CorrectionDetails<'c> {
+    /// Name of the dictionary or config field, like `typos`, `varcon`, or `extend-words`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<Cow<'c, str>>,
+    /// How accepted the correction is in its dialect, like `seldom` or `improper`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<Cow<'c, str>>,
+    /// Likelihood the correction is the intended word, from `0.0` to `1.0`.
+    pub confidence: f32,
+}
+
+impl<'c> CorrectionDetails<'c> {
+    pub fn into_owned(self) -> CorrectionDetails<'static> {
+        CorrectionDetails {
+            source: self.source.map(|s| Cow::Owned(s.into_owned())),
+            tag: self.tag.map(|t| Cow::Owned(t.into_owned())),
+            confidence: self.confidence,
+        }
+    }
+
+    pub fn borrow(&self) -> CorrectionDetails<'_> {
+        CorrectionDetails {
+            source: self.source.as_deref().map(Cow::Borrowed),
+            tag: self.tag.as_deref().map(Cow::Borrowed),
+            confidence: self.confidence,
+        }
+    }
+}
+
+/// A known word that is close to an unknown one.
+#[derive(Clone, PartialEq, Eq, Debug, serde::Serialize)]
+pub struct Suggestion<'c> {
+    pub word: Cow<'c, str>,
+    /// Weighted edit distance from the unknown word, where lower is closer.
+    pub distance: u32,
+}
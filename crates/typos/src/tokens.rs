@@ -4,6 +4,11 @@ use bstr::ByteSlice;
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TokenizerBuilder {
     unicode: bool,
+    ignore_re: Vec<PatternRegex>,
+    c_escapes: bool,
+    printf_placeholders: bool,
+    brace_placeholders: bool,
+    segment_words: bool,
 }
 
 impl TokenizerBuilder {
@@ -17,15 +22,88 @@ impl TokenizerBuilder {
         self
     }
 
+    /// Specify patterns whose matches are skipped when looking for Identifiers.
+    pub fn ignore_re(&mut self, re: impl IntoIterator<Item = regex::bytes::Regex>) -> &mut Self {
+        self.ignore_re = re.into_iter().map(PatternRegex).collect();
+        self
+    }
+
+    /// Specify that C-style escape sequences (`\n`, `\x41`, `\u{00e9}`, etc) separate Identifiers.
+    pub fn c_escapes(&mut self, yes: bool) -> &mut Self {
+        self.c_escapes = yes;
+        self
+    }
+
+    /// Specify that printf-style placeholders (`%d`, `%-5.2f`, `%(name)s`, etc) separate Identifiers.
+    pub fn printf_placeholders(&mut self, yes: bool) -> &mut Self {
+        self.printf_placeholders = yes;
+        self
+    }
+
+    /// Specify that brace placeholders (`{}`, `{0}`, `{:>8}`, `{name:?}`, etc) separate Identifiers.
+    ///
+    /// Named arguments are still treated as Identifiers.
+    pub fn brace_placeholders(&mut self, yes: bool) -> &mut Self {
+        self.brace_placeholders = yes;
+        self
+    }
+
+    /// Specify that lowercase Words without a case or separator boundary, like `recievedata`,
+    /// should be split into the Dictionary's valid words to find typos inside of them.
+    pub fn segment_words(&mut self, yes: bool) -> &mut Self {
+        self.segment_words = yes;
+        self
+    }
+
     pub fn build(&self) -> Tokenizer {
-        let TokenizerBuilder { unicode } = self.clone();
-        Tokenizer { unicode }
+        let TokenizerBuilder {
+            unicode,
+            ignore_re,
+            c_escapes,
+            printf_placeholders,
+            brace_placeholders,
+            segment_words,
+        } = self.clone();
+        let ignore_re = ignore_re.into_iter().map(|re| re.0).collect();
+        Tokenizer {
+            unicode,
+            ignore_re,
+            c_escapes,
+            printf_placeholders,
+            brace_placeholders,
+            segment_words,
+        }
     }
 }
 
 impl Default for TokenizerBuilder {
     fn default() -> Self {
-        Self { unicode: true }
+        Self {
+            unicode: true,
+            ignore_re: Vec::new(),
+            c_escapes: false,
+            printf_placeholders: false,
+            brace_placeholders: false,
+            segment_words: false,
+        }
+    }
+}
+
+/// A regex compared and hashed by its pattern.
+#[derive(Debug, Clone)]
+struct PatternRegex(regex::bytes::Regex);
+
+impl PartialEq for PatternRegex {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_str() == other.0.as_str()
+    }
+}
+
+impl Eq for PatternRegex {}
+
+impl std::hash::Hash for PatternRegex {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.as_str().hash(state);
     }
 }
 
@@ -33,6 +111,11 @@ impl Default for TokenizerBuilder {
 #[derive(Debug, Clone)]
 pub struct Tokenizer {
     unicode: bool,
+    ignore_re: Vec<regex::bytes::Regex>,
+    c_escapes: bool,
+    printf_placeholders: bool,
+    brace_placeholders: bool,
+    segment_words: bool,
 }
 
 impl Tokenizer {
@@ -41,23 +124,76 @@ impl Tokenizer {
     }
 
     pub fn parse_str<'c>(&'c self, content: &'c str) -> impl Iterator<Item = Identifier<'c>> {
+        if !self.ignore_re.is_empty() {
+            // Matches aren't guaranteed to fall on `char` boundaries
+            return itertools::Either::Left(self.parse_bytes(content.as_bytes()));
+        }
+
+        let options = self.options();
         let iter = if self.unicode && !ByteSlice::is_ascii(content.as_bytes()) {
-            itertools::Either::Left(unicode_parser::iter_identifiers(content))
+            itertools::Either::Left(unicode_parser::iter_identifiers(content, options))
         } else {
-            itertools::Either::Right(ascii_parser::iter_identifiers(content.as_bytes()))
+            itertools::Either::Right(ascii_parser::iter_identifiers(content.as_bytes(), options))
         };
-        iter.map(move |identifier| self.transform(identifier, content.as_bytes()))
+        itertools::Either::Right(
+            iter.map(move |identifier| self.transform(identifier, content.as_bytes())),
+        )
     }
 
     pub fn parse_bytes<'c>(&'c self, content: &'c [u8]) -> impl Iterator<Item = Identifier<'c>> {
-        let iter = if self.unicode && !ByteSlice::is_ascii(content) {
-            let iter =
-                Utf8Chunks::new(content).flat_map(move |c| unicode_parser::iter_identifiers(c));
-            itertools::Either::Left(iter)
-        } else {
-            itertools::Either::Right(ascii_parser::iter_identifiers(content))
-        };
-        iter.map(move |identifier| self.transform(identifier, content))
+        let options = self.options();
+        self.unignored(content)
+            .flat_map(move |chunk| {
+                if self.unicode && !ByteSlice::is_ascii(chunk) {
+                    let iter = Utf8Chunks::new(chunk)
+                        .flat_map(move |c| unicode_parser::iter_identifiers(c, options));
+                    itertools::Either::Left(iter)
+                } else {
+                    itertools::Either::Right(ascii_parser::iter_identifiers(chunk, options))
+                }
+            })
+            .map(move |identifier| self.transform(identifier, content))
+    }
+
+    /// Whether lowercase Words should be split into the Dictionary's valid words.
+    pub fn segment_words(&self) -> bool {
+        self.segment_words
+    }
+
+    fn options(&self) -> parser::Options {
+        parser::Options {
+            c_escapes: self.c_escapes,
+            printf_placeholders: self.printf_placeholders,
+            brace_placeholders: self.brace_placeholders,
+        }
+    }
+
+    /// Split `content` around the matches of `ignore_re`.
+    fn unignored<'c>(&self, content: &'c [u8]) -> impl Iterator<Item = &'c [u8]> {
+        if self.ignore_re.is_empty() {
+            return itertools::Either::Left(Some(content).into_iter());
+        }
+
+        let mut ignored: Vec<_> = self
+            .ignore_re
+            .iter()
+            .flat_map(|re| re.find_iter(content))
+            .map(|m| m.range())
+            .collect();
+        ignored.sort_unstable_by_key(|r| r.start);
+
+        let mut chunks = Vec::with_capacity(ignored.len() + 1);
+        let mut start = 0;
+        for range in ignored {
+            if start < range.start {
+                chunks.push(&content[start..range.start]);
+            }
+            start = start.max(range.end);
+        }
+        if start < content.len() {
+            chunks.push(&content[start..]);
+        }
+        itertools::Either::Right(chunks.into_iter())
     }
 
     fn transform<'i>(&self, identifier: &'i str, content: &[u8]) -> Identifier<'i> {
@@ -129,7 +265,28 @@ mod parser {
     use nom::sequence::*;
     use nom::{AsChar, IResult};
 
-    pub(crate) fn next_identifier<T>(input: T) -> IResult<T, T>
+    /// Language-specific syntax to treat as separators.
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+    pub(crate) struct Options {
+        pub(crate) c_escapes: bool,
+        pub(crate) printf_placeholders: bool,
+        pub(crate) brace_placeholders: bool,
+    }
+
+    impl Options {
+        /// Whether `c` starts a sequence that has to be parsed rather than skipped as a separator
+        #[inline]
+        fn is_sequence_start(&self, c: char) -> bool {
+            match c {
+                '\\' => self.c_escapes,
+                '%' => self.printf_placeholders,
+                '{' => self.brace_placeholders,
+                _ => false,
+            }
+        }
+    }
+
+    pub(crate) fn next_identifier<T>(input: T, options: Options) -> IResult<T, T>
     where
         T: nom::InputTakeAtPosition
             + nom::InputTake
@@ -144,7 +301,10 @@ mod parser {
         <T as nom::InputTakeAtPosition>::Item: AsChar + Copy,
         <T as nom::InputIter>::Item: AsChar + Copy,
     {
-        preceded(ignore, identifier)(input)
+        preceded(
+            ignore(options),
+            alt((named_brace_placeholder(options), identifier)),
+        )(input)
     }
 
     fn identifier<T>(input: T) -> IResult<T, T>
@@ -159,7 +319,7 @@ mod parser {
         take_while1(is_xid_continue)(input)
     }
 
-    fn ignore<T>(input: T) -> IResult<T, T>
+    fn ignore<T>(options: Options) -> impl FnMut(T) -> IResult<T, T>
     where
         T: nom::InputTakeAtPosition
             + nom::InputTake
@@ -174,24 +334,104 @@ mod parser {
         <T as nom::InputTakeAtPosition>::Item: AsChar + Copy,
         <T as nom::InputIter>::Item: AsChar + Copy,
     {
-        take_many0(alt((
-            terminated(uuid_literal, sep1),
-            terminated(hash_literal, sep1),
-            terminated(hex_literal, sep1),
-            terminated(dec_literal, sep1),
-            terminated(base64_literal, sep1),
-            terminated(email_literal, sep1),
-            terminated(url_literal, sep1),
-            sep1,
-        )))(input)
+        move |input: T| {
+            take_many0(alt((
+                terminated(uuid_literal, boundary(options)),
+                // A percentage, like `50%off`, rather than a printf placeholder
+                recognize(pair(dec_literal, char('%'))),
+                terminated(hash_literal, boundary(options)),
+                terminated(hex_literal, boundary(options)),
+                terminated(dec_literal, boundary(options)),
+                terminated(base64_literal, boundary(options)),
+                terminated(email_literal, boundary(options)),
+                terminated(url_literal, boundary(options)),
+                c_escape(options),
+                printf_placeholder(options),
+                brace_placeholder(options),
+                sep1(options),
+                // A sequence start that didn't parse is still a separator, unless it starts a
+                // named placeholder whose name is left to be checked
+                preceded(
+                    not(named_brace_placeholder(options)),
+                    sequence_start(options),
+                ),
+            )))(input)
+        }
     }
 
-    fn sep1<T>(input: T) -> IResult<T, T>
+    fn sep1<T>(options: Options) -> impl FnMut(T) -> IResult<T, T>
     where
         T: nom::InputTakeAtPosition,
         <T as nom::InputTakeAtPosition>::Item: AsChar + Copy,
     {
-        take_till1(is_xid_continue)(input)
+        move |input: T| {
+            take_till1(|c: <T as nom::InputTakeAtPosition>::Item| {
+                is_xid_continue(c) || options.is_sequence_start(c.as_char())
+            })(input)
+        }
+    }
+
+    /// The end of a literal, without consuming the start of a sequence.
+    fn boundary<T>(options: Options) -> impl FnMut(T) -> IResult<T, T>
+    where
+        T: nom::InputTakeAtPosition + nom::InputIter + nom::Slice<std::ops::RangeFrom<usize>>,
+        T: nom::Offset + nom::Slice<std::ops::RangeTo<usize>> + Clone,
+        <T as nom::InputTakeAtPosition>::Item: AsChar + Copy,
+        <T as nom::InputIter>::Item: AsChar + Copy,
+    {
+        move |input: T| alt((sep1(options), peek(sequence_start(options))))(input)
+    }
+
+    fn sequence_start<T>(options: Options) -> impl FnMut(T) -> IResult<T, T>
+    where
+        T: nom::InputIter
+            + nom::Slice<std::ops::RangeFrom<usize>>
+            + nom::Slice<std::ops::RangeTo<usize>>
+            + nom::Offset
+            + Clone,
+        <T as nom::InputIter>::Item: AsChar + Copy,
+    {
+        move |input: T| recognize(satisfy(|c| options.is_sequence_start(c)))(input)
+    }
+
+    fn c_escape<T>(options: Options) -> impl FnMut(T) -> IResult<T, T>
+    where
+        T: nom::InputTakeAtPosition
+            + nom::InputTake
+            + nom::InputIter
+            + nom::InputLength
+            + nom::Offset
+            + nom::Slice<std::ops::RangeTo<usize>>
+            + nom::Slice<std::ops::RangeFrom<usize>>
+            + Clone,
+        <T as nom::InputTakeAtPosition>::Item: AsChar + Copy,
+        <T as nom::InputIter>::Item: AsChar + Copy,
+    {
+        move |input: T| {
+            if !options.c_escapes {
+                return Err(nom::Err::Error(nom::error::Error::new(
+                    input,
+                    nom::error::ErrorKind::Escaped,
+                )));
+            }
+
+            recognize(preceded(
+                char('\\'),
+                alt((
+                    recognize(pair(char('x'), take_while_m_n(1, 2, AsChar::is_hex_digit))),
+                    recognize(tuple((
+                        char('u'),
+                        char('{'),
+                        take_while_m_n(1, 6, AsChar::is_hex_digit),
+                        char('}'),
+                    ))),
+                    recognize(pair(char('u'), take_while_m_n(4, 4, AsChar::is_hex_digit))),
+                    recognize(pair(char('U'), take_while_m_n(8, 8, AsChar::is_hex_digit))),
+                    take_while_m_n(1, 3, AsChar::is_oct_digit),
+                    recognize(satisfy(is_escape_char)),
+                )),
+            ))(input)
+        }
     }
 
     fn dec_literal<T>(input: T) -> IResult<T, T>
@@ -444,6 +684,180 @@ mod parser {
         ('a'..='z').contains(&c) || ('0'..='9').contains(&c) || "+.-".find(c).is_some()
     }
 
+    fn printf_placeholder<T>(options: Options) -> impl FnMut(T) -> IResult<T, T>
+    where
+        T: nom::InputTakeAtPosition
+            + nom::InputTake
+            + nom::InputIter
+            + nom::InputLength
+            + nom::Offset
+            + nom::Slice<std::ops::RangeTo<usize>>
+            + nom::Slice<std::ops::RangeFrom<usize>>
+            + Clone,
+        <T as nom::InputTakeAtPosition>::Item: AsChar + Copy,
+        <T as nom::InputIter>::Item: AsChar + Copy,
+    {
+        move |input: T| {
+            if !options.printf_placeholders {
+                return Err(nom::Err::Error(nom::error::Error::new(
+                    input,
+                    nom::error::ErrorKind::Char,
+                )));
+            }
+
+            recognize(tuple((
+                char('%'),
+                // Python's mapping key
+                opt(tuple((char('('), take_while(is_xid_continue), char(')')))),
+                take_while(is_printf_flag),
+                take_while(is_printf_width),
+                opt(pair(char('.'), take_while(is_printf_width))),
+                take_while_m_n(0, 2, is_printf_length),
+                satisfy(is_printf_conversion),
+            )))(input)
+        }
+    }
+
+    fn brace_placeholder<T>(options: Options) -> impl FnMut(T) -> IResult<T, T>
+    where
+        T: nom::InputTakeAtPosition
+            + nom::InputTake
+            + nom::InputIter
+            + nom::InputLength
+            + nom::Offset
+            + nom::Slice<std::ops::RangeTo<usize>>
+            + nom::Slice<std::ops::RangeFrom<usize>>
+            + Clone,
+        <T as nom::InputTakeAtPosition>::Item: AsChar + Copy,
+        <T as nom::InputIter>::Item: AsChar + Copy,
+    {
+        move |input: T| {
+            if !options.brace_placeholders {
+                return Err(nom::Err::Error(nom::error::Error::new(
+                    input,
+                    nom::error::ErrorKind::Char,
+                )));
+            }
+
+            // Positional: `{}`, `{0}`, `{0,-10}`, `{:>8}`
+            recognize(tuple((
+                char('{'),
+                take_while(AsChar::is_dec_digit),
+                opt(pair(char(','), take_while(is_dec_digit_with_sign))),
+                opt(format_spec),
+                char('}'),
+            )))(input)
+        }
+    }
+
+    /// Named brace placeholder, like `{count:>8}`, returning the name so it gets checked
+    fn named_brace_placeholder<T>(options: Options) -> impl FnMut(T) -> IResult<T, T>
+    where
+        T: nom::InputTakeAtPosition
+            + nom::InputTake
+            + nom::InputIter
+            + nom::InputLength
+            + nom::Offset
+            + nom::Slice<std::ops::RangeTo<usize>>
+            + nom::Slice<std::ops::RangeFrom<usize>>
+            + Clone,
+        <T as nom::InputTakeAtPosition>::Item: AsChar + Copy,
+        <T as nom::InputIter>::Item: AsChar + Copy,
+    {
+        move |input: T| {
+            if !options.brace_placeholders {
+                return Err(nom::Err::Error(nom::error::Error::new(
+                    input,
+                    nom::error::ErrorKind::Char,
+                )));
+            }
+
+            delimited(
+                char('{'),
+                identifier,
+                tuple((
+                    opt(pair(char(','), take_while(is_dec_digit_with_sign))),
+                    opt(format_spec),
+                    char('}'),
+                )),
+            )(input)
+        }
+    }
+
+    fn format_spec<T>(input: T) -> IResult<T, T>
+    where
+        T: nom::InputTakeAtPosition
+            + nom::InputIter
+            + nom::Offset
+            + nom::Slice<std::ops::RangeTo<usize>>
+            + nom::Slice<std::ops::RangeFrom<usize>>
+            + Clone,
+        <T as nom::InputTakeAtPosition>::Item: AsChar + Copy,
+        <T as nom::InputIter>::Item: AsChar,
+    {
+        // `[[fill]align][sign]['#']['0'][width][grouping]['.' precision][type]`, covering Rust,
+        // Python, and C# (`N2`)
+        recognize(tuple((
+            char(':'),
+            opt(alt((
+                recognize(pair(satisfy(|c| c != '{' && c != '}'), satisfy(is_align))),
+                recognize(satisfy(is_align)),
+            ))),
+            opt(satisfy(|c| "+- ".find(c).is_some())),
+            opt(char('#')),
+            take_while(AsChar::is_dec_digit),
+            opt(char('$')),
+            opt(satisfy(|c| c == ',' || c == '_')),
+            opt(tuple((
+                char('.'),
+                take_while(is_printf_width),
+                opt(char('$')),
+            ))),
+            opt(pair(
+                satisfy(|c| c.is_ascii_alphabetic()),
+                take_while(AsChar::is_dec_digit),
+            )),
+            opt(char('?')),
+        )))(input)
+    }
+
+    #[inline]
+    fn is_escape_char(c: char) -> bool {
+        "abefnrtv\\'\"?".find(c).is_some()
+    }
+
+    #[inline]
+    fn is_printf_flag(i: impl AsChar + Copy) -> bool {
+        let c = i.as_char();
+        "-+#0".find(c).is_some()
+    }
+
+    #[inline]
+    fn is_printf_width(i: impl AsChar + Copy) -> bool {
+        i.is_dec_digit() || i.as_char() == '*'
+    }
+
+    #[inline]
+    fn is_printf_length(i: impl AsChar + Copy) -> bool {
+        let c = i.as_char();
+        "hlLqjzt".find(c).is_some()
+    }
+
+    #[inline]
+    fn is_printf_conversion(c: char) -> bool {
+        "diouxXeEfFgGaAcsSpn%@".find(c).is_some()
+    }
+
+    #[inline]
+    fn is_dec_digit_with_sign(i: impl AsChar + Copy) -> bool {
+        i.is_dec_digit() || i.as_char() == '-'
+    }
+
+    #[inline]
+    fn is_align(c: char) -> bool {
+        "<>^=".find(c).is_some()
+    }
+
     #[inline]
     fn is_xid_continue(i: impl AsChar + Copy) -> bool {
         let c = i.as_char();
@@ -460,9 +874,13 @@ mod parser {
 
 mod unicode_parser {
     use super::parser::next_identifier;
+    use super::parser::Options;
 
-    pub(crate) fn iter_identifiers(mut input: &str) -> impl Iterator<Item = &str> {
-        std::iter::from_fn(move || match next_identifier(input) {
+    pub(crate) fn iter_identifiers(
+        mut input: &str,
+        options: Options,
+    ) -> impl Iterator<Item = &str> {
+        std::iter::from_fn(move || match next_identifier(input, options) {
             Ok((i, o)) => {
                 input = i;
                 debug_assert_ne!(o, "");
@@ -475,9 +893,13 @@ mod unicode_parser {
 
 mod ascii_parser {
     use super::parser::next_identifier;
+    use super::parser::Options;
 
-    pub(crate) fn iter_identifiers(mut input: &[u8]) -> impl Iterator<Item = &str> {
-        std::iter::from_fn(move || match next_identifier(input) {
+    pub(crate) fn iter_identifiers(
+        mut input: &[u8],
+        options: Options,
+    ) -> impl Iterator<Item = &str> {
+        std::iter::from_fn(move || match next_identifier(input, options) {
             Ok((i, o)) => {
                 input = i;
                 debug_assert_ne!(o, b"");
@@ -924,6 +1346,183 @@ mod test {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn tokenize_ignore_re() {
+        let parser = TokenizerBuilder::new()
+            .ignore_re(vec![regex::bytes::Regex::new("[A-Z]{4}-[0-9]+").unwrap()])
+            .build();
+
+        let input = "Good ABCD-1234 Bye";
+        let expected: Vec<Identifier> = vec![
+            Identifier::new_unchecked("Good", Case::None, 0),
+            Identifier::new_unchecked("Bye", Case::None, 15),
+        ];
+        let actual: Vec<_> = parser.parse_bytes(input.as_bytes()).collect();
+        assert_eq!(expected, actual);
+        let actual: Vec<_> = parser.parse_str(input).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn builder_compares_ignore_re_patterns() {
+        let builder = |pattern| {
+            let mut builder = TokenizerBuilder::new();
+            builder.ignore_re(vec![regex::bytes::Regex::new(pattern).unwrap()]);
+            builder
+        };
+        assert_eq!(builder("[0-9]+"), builder("[0-9]+"));
+        assert_ne!(builder("[0-9]+"), builder("[a-z]+"));
+    }
+
+    #[test]
+    fn tokenize_ignore_re_overlapping() {
+        let parser = TokenizerBuilder::new()
+            .ignore_re(vec![
+                regex::bytes::Regex::new("sku_[a-z]+").unwrap(),
+                regex::bytes::Regex::new("_abc").unwrap(),
+            ])
+            .build();
+
+        let input = "Good sku_abcdef Bye";
+        let expected: Vec<Identifier> = vec![
+            Identifier::new_unchecked("Good", Case::None, 0),
+            Identifier::new_unchecked("Bye", Case::None, 16),
+        ];
+        let actual: Vec<_> = parser.parse_bytes(input.as_bytes()).collect();
+        assert_eq!(expected, actual);
+        let actual: Vec<_> = parser.parse_str(input).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn tokenize_c_escapes() {
+        let parser = TokenizerBuilder::new().c_escapes(true).build();
+
+        let input = r#""\nescapes\tand\x41more\u{e9}done\\next \qux 0xFF\r""#;
+        let expected: Vec<Identifier> = vec![
+            Identifier::new_unchecked("escapes", Case::None, 3),
+            Identifier::new_unchecked("and", Case::None, 12),
+            Identifier::new_unchecked("more", Case::None, 19),
+            Identifier::new_unchecked("done", Case::None, 29),
+            Identifier::new_unchecked("next", Case::None, 35),
+            Identifier::new_unchecked("qux", Case::None, 41),
+        ];
+        let actual: Vec<_> = parser.parse_bytes(input.as_bytes()).collect();
+        assert_eq!(expected, actual);
+        let actual: Vec<_> = parser.parse_str(input).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn tokenize_c_escapes_unicode() {
+        let parser = TokenizerBuilder::new().c_escapes(true).build();
+
+        let input = r#""Grüße\nWelt""#;
+        let expected: Vec<Identifier> = vec![
+            Identifier::new_unchecked("Grüße", Case::None, 1),
+            Identifier::new_unchecked("Welt", Case::None, 10),
+        ];
+        let actual: Vec<_> = parser.parse_bytes(input.as_bytes()).collect();
+        assert_eq!(expected, actual);
+        let actual: Vec<_> = parser.parse_str(input).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn tokenize_c_escapes_disabled() {
+        let parser = TokenizerBuilder::new().build();
+
+        let input = r#""\nescapes\tand""#;
+        let expected: Vec<Identifier> = vec![
+            Identifier::new_unchecked("nescapes", Case::None, 2),
+            Identifier::new_unchecked("tand", Case::None, 11),
+        ];
+        let actual: Vec<_> = parser.parse_bytes(input.as_bytes()).collect();
+        assert_eq!(expected, actual);
+        let actual: Vec<_> = parser.parse_str(input).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn tokenize_printf_placeholders() {
+        let parser = TokenizerBuilder::new().printf_placeholders(true).build();
+
+        let input = r#""%dfiles processed, %(name)sfoo, %-5.2fdays, 100%% done""#;
+        let expected: Vec<Identifier> = vec![
+            Identifier::new_unchecked("files", Case::None, 3),
+            Identifier::new_unchecked("processed", Case::None, 9),
+            Identifier::new_unchecked("foo", Case::None, 28),
+            Identifier::new_unchecked("days", Case::None, 39),
+            Identifier::new_unchecked("done", Case::None, 51),
+        ];
+        let actual: Vec<_> = parser.parse_bytes(input.as_bytes()).collect();
+        assert_eq!(expected, actual);
+        let actual: Vec<_> = parser.parse_str(input).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn tokenize_brace_placeholders() {
+        let parser = TokenizerBuilder::new().brace_placeholders(true).build();
+
+        let input = r#""{0}items {count}th {:>8}wide {name:?}less {0,-10:N2}cols std::fmt""#;
+        let expected: Vec<Identifier> = vec![
+            Identifier::new_unchecked("items", Case::None, 4),
+            Identifier::new_unchecked("count", Case::None, 11),
+            Identifier::new_unchecked("th", Case::None, 17),
+            Identifier::new_unchecked("wide", Case::None, 25),
+            Identifier::new_unchecked("name", Case::None, 31),
+            Identifier::new_unchecked("less", Case::None, 38),
+            Identifier::new_unchecked("cols", Case::None, 53),
+            Identifier::new_unchecked("std", Case::None, 58),
+            Identifier::new_unchecked("fmt", Case::None, 63),
+        ];
+        let actual: Vec<_> = parser.parse_bytes(input.as_bytes()).collect();
+        assert_eq!(expected, actual);
+        let actual: Vec<_> = parser.parse_str(input).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn tokenize_placeholders_near_typos() {
+        let parser = TokenizerBuilder::new()
+            .printf_placeholders(true)
+            .brace_placeholders(true)
+            .build();
+
+        let input = r#""50%off, a x:thier} b, {x:thier}, {y:>8}ok""#;
+        let expected: Vec<Identifier> = vec![
+            Identifier::new_unchecked("off", Case::None, 4),
+            Identifier::new_unchecked("a", Case::None, 9),
+            Identifier::new_unchecked("x", Case::None, 11),
+            Identifier::new_unchecked("thier", Case::None, 13),
+            Identifier::new_unchecked("b", Case::None, 20),
+            Identifier::new_unchecked("x", Case::None, 24),
+            Identifier::new_unchecked("thier", Case::None, 26),
+            Identifier::new_unchecked("y", Case::None, 35),
+            Identifier::new_unchecked("ok", Case::None, 40),
+        ];
+        let actual: Vec<_> = parser.parse_bytes(input.as_bytes()).collect();
+        assert_eq!(expected, actual);
+        let actual: Vec<_> = parser.parse_str(input).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn tokenize_placeholders_disabled() {
+        let parser = TokenizerBuilder::new().build();
+
+        let input = r#""%dfiles {:>8}wide""#;
+        let expected: Vec<Identifier> = vec![
+            Identifier::new_unchecked("dfiles", Case::None, 2),
+            Identifier::new_unchecked("wide", Case::None, 14),
+        ];
+        let actual: Vec<_> = parser.parse_bytes(input.as_bytes()).collect();
+        assert_eq!(expected, actual);
+        let actual: Vec<_> = parser.parse_str(input).collect();
+        assert_eq!(expected, actual);
+    }
+
     #[test]
     fn tokenize_leading_digits() {
         let parser = TokenizerBuilder::new().build();
@@ -16,18 +16,11 @@ impl Format {
     pub(crate) fn reporter(
         self,
         stdout_palette: crate::report::Palette,
-        stderr_palette: crate::report::Palette,
     ) -> Box<dyn typos_cli::report::Report> {
         match self {
             Format::Silent => Box::new(crate::report::PrintSilent),
-            Format::Brief => Box::new(crate::report::PrintBrief {
-                stdout_palette,
-                stderr_palette,
-            }),
-            Format::Long => Box::new(crate::report::PrintLong {
-                stdout_palette,
-                stderr_palette,
-            }),
+            Format::Brief => Box::new(crate::report::PrintBrief { stdout_palette }),
+            Format::Long => Box::new(crate::report::PrintLong { stdout_palette }),
             Format::Json => Box::new(crate::report::PrintJson),
         }
     }
@@ -68,6 +61,11 @@ pub(crate) struct Args {
     /// Write fixes out
     pub(crate) write_changes: bool,
 
+    #[structopt(long)]
+    /// Also report spellings from dialects other than the one mostly used, like `colour` among
+    /// `color`s, where the `locale` is `en`
+    pub(crate) variant_consistency: bool,
+
     #[structopt(long, group = "mode")]
     /// Debug: Print each file that would be spellchecked.
     pub(crate) files: bool,
@@ -131,6 +129,18 @@ pub(crate) struct FileArgs {
     #[structopt(long, overrides_with("no-check-files"), hidden(true))]
     check_files: bool,
 
+    #[structopt(long, overrides_with("no-check-doubled-words"))]
+    /// Report words repeated across whitespace, like "the the".
+    check_doubled_words: bool,
+    #[structopt(long, overrides_with("check-doubled-words"), hidden(true))]
+    no_check_doubled_words: bool,
+
+    #[structopt(long, overrides_with("no-check-unknown-words"))]
+    /// Report words missing from the valid-word list, with suggestions.
+    check_unknown_words: bool,
+    #[structopt(long, overrides_with("check-unknown-words"), hidden(true))]
+    no_check_unknown_words: bool,
+
     #[structopt(long, overrides_with("no-unicode"), hidden(true))]
     unicode: bool,
     #[structopt(long, overrides_with("unicode"))]
@@ -142,6 +152,20 @@ pub(crate) struct FileArgs {
         possible_values(&config::Locale::variants()),
     )]
     pub(crate) locale: Option<config::Locale>,
+
+    #[structopt(
+        long,
+        possible_values(&config::LocaleStrictness::variants()),
+    )]
+    /// Which of the locale's less accepted spellings to report.
+    pub(crate) locale_strictness: Option<config::LocaleStrictness>,
+
+    #[structopt(
+        long,
+        possible_values(&config::CheckMode::variants()),
+    )]
+    /// Which parts of a file to verify.
+    pub(crate) check_mode: Option<config::CheckMode>,
 }
 
 impl FileArgs {
@@ -150,14 +174,21 @@ impl FileArgs {
             binary: self.binary(),
             check_filename: self.check_filename(),
             check_file: self.check_file(),
+            check_mode: self.check_mode,
+            check_doubled_words: self.check_doubled_words(),
+            check_unknown_words: self.check_unknown_words(),
+            severity: None,
             tokenizer: Some(config::TokenizerConfig {
                 unicode: self.unicode(),
                 ..Default::default()
             }),
             dict: Some(config::DictConfig {
                 locale: self.locale,
+                locale_strictness: self.locale_strictness,
                 ..Default::default()
             }),
+            word_severity: Default::default(),
+            forbid: Default::default(),
         }
     }
 
@@ -169,6 +200,14 @@ impl FileArgs {
         resolve_bool_arg(self.check_filenames, self.no_check_filenames)
     }
 
+    fn check_doubled_words(&self) -> Option<bool> {
+        resolve_bool_arg(self.check_doubled_words, self.no_check_doubled_words)
+    }
+
+    fn check_unknown_words(&self) -> Option<bool> {
+        resolve_bool_arg(self.check_unknown_words, self.no_check_unknown_words)
+    }
+
     fn unicode(&self) -> Option<bool> {
         resolve_bool_arg(self.unicode, self.no_unicode)
     }
@@ -46,31 +46,29 @@ fn run() -> proc_exit::ExitResult {
     } else {
         report::Palette::plain()
     };
-    let stderr_palette = if colored_stderr {
-        report::Palette::colored()
-    } else {
-        report::Palette::plain()
-    };
 
     if let Some(output_path) = args.dump_config.as_ref() {
         run_dump_config(&args, output_path)
     } else if args.type_list {
         run_type_list(&args)
     } else {
-        run_checks(&args, stdout_palette, stderr_palette)
+        run_checks(&args, stdout_palette)
     }
 }
 
 fn run_dump_config(args: &args::Args, output_path: &std::path::Path) -> proc_exit::ExitResult {
-    let global_cwd = std::env::current_dir()?;
+    let global_cwd = std::env::current_dir()?.canonicalize()?;
 
     let path = &args.path[0];
     let cwd = if path == std::path::Path::new("-") {
-        global_cwd
+        global_cwd.clone()
     } else if path.is_file() {
-        let mut cwd = path.canonicalize().with_code(proc_exit::Code::USAGE_ERR)?;
-        cwd.pop();
-        cwd
+        // Like a symlinked file's lookup, `cwd` is where it was found
+        path.parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or_else(|| std::path::Path::new("."))
+            .canonicalize()
+            .with_code(proc_exit::Code::USAGE_ERR)?
     } else {
         path.canonicalize().with_code(proc_exit::Code::USAGE_ERR)?
     };
@@ -79,6 +77,12 @@ fn run_dump_config(args: &args::Args, output_path: &std::path::Path) -> proc_exi
     let storage = typos_cli::policy::ConfigStorage::new();
     let mut engine = typos_cli::policy::ConfigEngine::new(&storage);
     engine.set_isolated(args.isolated);
+    engine.set_project_root(Some(global_cwd.clone()));
+    if !args.isolated {
+        let user =
+            typos_cli::config::Config::from_user_file().with_code(proc_exit::Code::CONFIG_ERR)?;
+        engine.set_user_config(user);
+    }
 
     let mut overrides = typos_cli::config::Config::default();
     if let Some(path) = args.custom_config.as_ref() {
@@ -106,15 +110,18 @@ fn run_dump_config(args: &args::Args, output_path: &std::path::Path) -> proc_exi
 }
 
 fn run_type_list(args: &args::Args) -> proc_exit::ExitResult {
-    let global_cwd = std::env::current_dir()?;
+    let global_cwd = std::env::current_dir()?.canonicalize()?;
 
     let path = &args.path[0];
     let cwd = if path == std::path::Path::new("-") {
-        global_cwd
+        global_cwd.clone()
     } else if path.is_file() {
-        let mut cwd = path.canonicalize().with_code(proc_exit::Code::USAGE_ERR)?;
-        cwd.pop();
-        cwd
+        // Like a symlinked file's lookup, `cwd` is where it was found
+        path.parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or_else(|| std::path::Path::new("."))
+            .canonicalize()
+            .with_code(proc_exit::Code::USAGE_ERR)?
     } else {
         path.canonicalize().with_code(proc_exit::Code::USAGE_ERR)?
     };
@@ -123,6 +130,12 @@ fn run_type_list(args: &args::Args) -> proc_exit::ExitResult {
     let storage = typos_cli::policy::ConfigStorage::new();
     let mut engine = typos_cli::policy::ConfigEngine::new(&storage);
     engine.set_isolated(args.isolated);
+    engine.set_project_root(Some(global_cwd.clone()));
+    if !args.isolated {
+        let user =
+            typos_cli::config::Config::from_user_file().with_code(proc_exit::Code::CONFIG_ERR)?;
+        engine.set_user_config(user);
+    }
 
     let mut overrides = typos_cli::config::Config::default();
     if let Some(path) = args.custom_config.as_ref() {
@@ -152,16 +165,18 @@ fn run_type_list(args: &args::Args) -> proc_exit::ExitResult {
     Ok(())
 }
 
-fn run_checks(
-    args: &args::Args,
-    stdout_palette: report::Palette,
-    stderr_palette: report::Palette,
-) -> proc_exit::ExitResult {
-    let global_cwd = std::env::current_dir()?;
+fn run_checks(args: &args::Args, stdout_palette: report::Palette) -> proc_exit::ExitResult {
+    let global_cwd = std::env::current_dir()?.canonicalize()?;
 
     let storage = typos_cli::policy::ConfigStorage::new();
     let mut engine = typos_cli::policy::ConfigEngine::new(&storage);
     engine.set_isolated(args.isolated);
+    engine.set_project_root(Some(global_cwd.clone()));
+    if !args.isolated {
+        let user =
+            typos_cli::config::Config::from_user_file().with_code(proc_exit::Code::CONFIG_ERR)?;
+        engine.set_user_config(user);
+    }
 
     let mut overrides = typos_cli::config::Config::default();
     if let Some(path) = args.custom_config.as_ref() {
@@ -172,51 +187,35 @@ fn run_checks(
     overrides.update(&args.config.to_config());
     engine.set_overrides(overrides);
 
+    if args.variant_consistency {
+        if args.path.iter().any(|p| p == std::path::Path::new("-")) {
+            return Err(proc_exit::Code::USAGE_ERR
+                .with_message("`--variant-consistency` can't read from stdin"));
+        }
+        // The majority dialect is needed before any file can be checked
+        let variant_consistency = typos_cli::file::VariantConsistency::new();
+        let tally = variant_consistency.tally();
+        for path in args.path.iter() {
+            walk_path(
+                args,
+                path,
+                &global_cwd,
+                &mut engine,
+                &tally,
+                &crate::report::PrintSilent,
+            )?;
+        }
+        engine.set_majority_locale(variant_consistency.majority());
+    }
+
     let mut typos_found = false;
     let mut errors_found = false;
     for path in args.path.iter() {
-        let cwd = if path == std::path::Path::new("-") {
-            global_cwd.clone()
-        } else if path.is_file() {
-            let mut cwd = path.canonicalize().with_code(proc_exit::Code::USAGE_ERR)?;
-            cwd.pop();
-            cwd
-        } else {
-            path.canonicalize().with_code(proc_exit::Code::USAGE_ERR)?
-        };
-
-        engine
-            .init_dir(&cwd)
-            .with_code(proc_exit::Code::CONFIG_ERR)?;
-        let walk_policy = engine.walk(&cwd);
-
-        let threads = if path.is_file() { 1 } else { args.threads };
-        let single_threaded = threads == 1;
-
-        let mut walk = ignore::WalkBuilder::new(path);
-        walk.threads(args.threads)
-            .hidden(walk_policy.ignore_hidden())
-            .ignore(walk_policy.ignore_dot())
-            .git_global(walk_policy.ignore_global())
-            .git_ignore(walk_policy.ignore_vcs())
-            .git_exclude(walk_policy.ignore_vcs())
-            .parents(walk_policy.ignore_parent());
-        if !walk_policy.extend_exclude.is_empty() {
-            let mut overrides = ignore::overrides::OverrideBuilder::new(".");
-            for pattern in walk_policy.extend_exclude.iter() {
-                overrides
-                    .add(&format!("!{}", pattern))
-                    .with_code(proc_exit::Code::CONFIG_ERR)?;
-            }
-            let overrides = overrides.build().with_code(proc_exit::Code::CONFIG_ERR)?;
-            walk.overrides(overrides);
-        }
-
         // HACK: Diff doesn't handle mixing content
         let output_reporter = if args.diff {
             Box::new(crate::report::PrintSilent)
         } else {
-            args.format.reporter(stdout_palette, stderr_palette)
+            args.format.reporter(stdout_palette)
         };
         let status_reporter = report::MessageStatus::new(output_reporter.as_ref());
         let reporter: &dyn typos_cli::report::Report = &status_reporter;
@@ -235,22 +234,14 @@ fn run_checks(
             &typos_cli::file::Typos
         };
 
-        if single_threaded {
-            typos_cli::file::walk_path(walk.build(), selected_checks, &engine, reporter)
-        } else {
-            typos_cli::file::walk_path_parallel(
-                walk.build_parallel(),
-                selected_checks,
-                &engine,
-                reporter,
-            )
-        }
-        .map_err(|e| {
-            e.io_error()
-                .map(|i| proc_exit::Code::from(i.kind()))
-                .unwrap_or_default()
-                .with_message(e)
-        })?;
+        walk_path(
+            args,
+            path,
+            &global_cwd,
+            &mut engine,
+            selected_checks,
+            reporter,
+        )?;
         if status_reporter.typos_found() {
             typos_found = true;
         }
@@ -272,6 +263,69 @@ fn run_checks(
     }
 }
 
+fn walk_path(
+    args: &args::Args,
+    path: &std::path::Path,
+    global_cwd: &std::path::Path,
+    engine: &mut typos_cli::policy::ConfigEngine,
+    checks: &dyn typos_cli::file::FileChecker,
+    reporter: &dyn typos_cli::report::Report,
+) -> proc_exit::ExitResult {
+    let cwd = if path == std::path::Path::new("-") {
+        global_cwd.to_owned()
+    } else if path.is_file() {
+        // Like a symlinked file's lookup, `cwd` is where it was found
+        path.parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or_else(|| std::path::Path::new("."))
+            .canonicalize()
+            .with_code(proc_exit::Code::USAGE_ERR)?
+    } else {
+        path.canonicalize().with_code(proc_exit::Code::USAGE_ERR)?
+    };
+
+    engine
+        .init_dir(&cwd)
+        .with_code(proc_exit::Code::CONFIG_ERR)?;
+    let walk_policy = engine.walk(&cwd);
+
+    let threads = if path.is_file() { 1 } else { args.threads };
+    let single_threaded = threads == 1;
+
+    let mut walk = ignore::WalkBuilder::new(path);
+    walk.threads(args.threads)
+        .hidden(walk_policy.ignore_hidden())
+        .ignore(walk_policy.ignore_dot())
+        .git_global(walk_policy.ignore_global())
+        .git_ignore(walk_policy.ignore_vcs())
+        .git_exclude(walk_policy.ignore_vcs())
+        .parents(walk_policy.ignore_parent());
+    if !walk_policy.extend_exclude.is_empty() {
+        let mut overrides = ignore::overrides::OverrideBuilder::new(".");
+        for pattern in walk_policy.extend_exclude.iter() {
+            overrides
+                .add(&format!("!{}", pattern))
+                .with_code(proc_exit::Code::CONFIG_ERR)?;
+        }
+        let overrides = overrides.build().with_code(proc_exit::Code::CONFIG_ERR)?;
+        walk.overrides(overrides);
+    }
+
+    if single_threaded {
+        typos_cli::file::walk_path(walk.build(), checks, engine, reporter)
+    } else {
+        typos_cli::file::walk_path_parallel(walk.build_parallel(), checks, engine, reporter)
+    }
+    .map_err(|e| {
+        e.io_error()
+            .map(|i| proc_exit::Code::from(i.kind()))
+            .unwrap_or_default()
+            .with_message(e)
+    })?;
+
+    Ok(())
+}
+
 fn init_logging(level: Option<log::Level>, colored: bool) {
     if let Some(level) = level {
         let mut builder = env_logger::Builder::new();
@@ -3,7 +3,7 @@
 use std::io::{self, Write};
 use std::sync::atomic;
 
-use typos_cli::report::{Context, Message, Report, Typo};
+use typos_cli::report::{Context, Forbidden, Message, Report, Typo};
 
 #[derive(Copy, Clone, Debug)]
 pub struct Palette {
@@ -59,7 +59,8 @@ impl<'r> MessageStatus<'r> {
 
 impl<'r> Report for MessageStatus<'r> {
     fn report(&self, msg: Message) -> Result<(), std::io::Error> {
-        if msg.is_correction() {
+        // Only errors fail the run
+        if msg.is_correction() && msg.severity() == Some(typos_cli::config::Severity::Error) {
             self.typos_found.store(true, atomic::Ordering::Relaxed);
         }
         if msg.is_error() {
@@ -80,7 +81,6 @@ impl Report for PrintSilent {
 
 pub struct PrintBrief {
     pub stdout_palette: Palette,
-    pub stderr_palette: Palette,
 }
 
 impl Report for PrintBrief {
@@ -90,6 +90,7 @@ impl Report for PrintBrief {
                 log::info!("{}", msg);
             }
             Message::Typo(msg) => print_brief_correction(msg, self.stdout_palette)?,
+            Message::Forbidden(msg) => print_brief_forbidden(msg, self.stdout_palette)?,
             Message::File(msg) => {
                 writeln!(io::stdout(), "{}", msg.path.display())?;
             }
@@ -107,7 +108,6 @@ impl Report for PrintBrief {
 
 pub struct PrintLong {
     pub stdout_palette: Palette,
-    pub stderr_palette: Palette,
 }
 
 impl Report for PrintLong {
@@ -117,6 +117,7 @@ impl Report for PrintLong {
                 log::info!("{}", msg);
             }
             Message::Typo(msg) => print_long_correction(msg, self.stdout_palette)?,
+            Message::Forbidden(msg) => print_long_forbidden(msg, self.stdout_palette)?,
             Message::File(msg) => {
                 writeln!(io::stdout(), "{}", msg.path.display())?;
             }
@@ -140,16 +141,18 @@ fn print_brief_correction(msg: &Typo, palette: Palette) -> Result<(), std::io::E
         true,
     )
     .count();
+    let severity = brief_severity(msg.severity);
     match &msg.corrections {
         typos::Status::Valid => {}
         typos::Status::Invalid => {
             let divider = ":";
             writeln!(
                 io::stdout(),
-                "{}{}{}: {}",
+                "{}{}{}: {}{}",
                 palette.info.paint(context_display(&msg.context)),
                 palette.info.paint(divider),
                 palette.info.paint(column),
+                severity,
                 palette
                     .strong
                     .paint(format_args!("`{}` is disallowed:", msg.typo)),
@@ -159,10 +162,11 @@ fn print_brief_correction(msg: &Typo, palette: Palette) -> Result<(), std::io::E
             let divider = ":";
             writeln!(
                 io::stdout(),
-                "{}{}{}: {}",
+                "{}{}{}: {}{}",
                 palette.info.paint(context_display(&msg.context)),
                 palette.info.paint(divider),
                 palette.info.paint(column),
+                severity,
                 palette.strong.paint(format_args!(
                     "`{}` -> {}",
                     msg.typo,
@@ -170,11 +174,39 @@ fn print_brief_correction(msg: &Typo, palette: Palette) -> Result<(), std::io::E
                 )),
             )?;
         }
+        typos::Status::Suggestions(suggestions) => {
+            let divider = ":";
+            writeln!(
+                io::stdout(),
+                "{}{}{}: {}{}",
+                palette.info.paint(context_display(&msg.context)),
+                palette.info.paint(divider),
+                palette.info.paint(column),
+                severity,
+                palette.strong.paint(format_args!(
+                    "`{}` is unknown{}",
+                    msg.typo,
+                    did_you_mean(suggestions)
+                )),
+            )?;
+        }
+        _ => {}
     }
 
     Ok(())
 }
 
+fn did_you_mean(suggestions: &[typos::Suggestion<'_>]) -> String {
+    if suggestions.is_empty() {
+        String::new()
+    } else {
+        format!(
+            ", did you mean {}?",
+            itertools::join(suggestions.iter().map(|s| format!("`{}`", s.word)), ", ")
+        )
+    }
+}
+
 fn print_long_correction(msg: &Typo, palette: Palette) -> Result<(), std::io::Error> {
     let stdout = io::stdout();
     let mut handle = stdout.lock();
@@ -183,13 +215,14 @@ fn print_long_correction(msg: &Typo, palette: Palette) -> Result<(), std::io::Er
     let line = line.replace("\t", " ");
     let start = String::from_utf8_lossy(&msg.buffer[0..msg.byte_offset]);
     let column = unicode_segmentation::UnicodeSegmentation::graphemes(start.as_ref(), true).count();
+    let style = severity_style(msg.severity, palette);
     match &msg.corrections {
         typos::Status::Valid => {}
         typos::Status::Invalid => {
             writeln!(
                 handle,
                 "{}: {}",
-                palette.error.paint("error"),
+                style.paint(msg.severity),
                 palette
                     .strong
                     .paint(format_args!("`{}` is disallowed`", msg.typo))
@@ -199,7 +232,7 @@ fn print_long_correction(msg: &Typo, palette: Palette) -> Result<(), std::io::Er
             writeln!(
                 handle,
                 "{}: {}",
-                palette.error.paint("error"),
+                style.paint(msg.severity),
                 palette.strong.paint(format_args!(
                     "`{}` should be {}",
                     msg.typo,
@@ -207,22 +240,142 @@ fn print_long_correction(msg: &Typo, palette: Palette) -> Result<(), std::io::Er
                 ))
             )?;
         }
+        typos::Status::Suggestions(suggestions) => {
+            writeln!(
+                handle,
+                "{}: {}",
+                style.paint(msg.severity),
+                palette.strong.paint(format_args!(
+                    "`{}` is unknown{}",
+                    msg.typo,
+                    did_you_mean(suggestions)
+                ))
+            )?;
+        }
+        _ => {}
     }
+    print_long_context(
+        &mut handle,
+        &msg.context,
+        &line,
+        column,
+        msg.typo,
+        style,
+        palette,
+    )?;
+
+    Ok(())
+}
+
+fn print_brief_forbidden(msg: &Forbidden, palette: Palette) -> Result<(), std::io::Error> {
+    let line = String::from_utf8_lossy(msg.buffer.as_ref());
+    let line = line.replace("\t", " ");
+    let column = unicode_segmentation::UnicodeSegmentation::graphemes(
+        line.get(0..msg.byte_offset).unwrap(),
+        true,
+    )
+    .count();
+    let divider = ":";
+    let message = msg.message.map(|m| format!(": {}", m)).unwrap_or_default();
+    writeln!(
+        io::stdout(),
+        "{}{}{}: {}{}{}",
+        palette.info.paint(context_display(&msg.context)),
+        palette.info.paint(divider),
+        palette.info.paint(column),
+        brief_severity(msg.severity),
+        palette.strong.paint(forbidden_summary(msg)),
+        message,
+    )?;
+
+    Ok(())
+}
+
+fn print_long_forbidden(msg: &Forbidden, palette: Palette) -> Result<(), std::io::Error> {
+    let stdout = io::stdout();
+    let mut handle = stdout.lock();
+
+    let line = String::from_utf8_lossy(msg.buffer.as_ref());
+    let line = line.replace("\t", " ");
+    let start = String::from_utf8_lossy(&msg.buffer[0..msg.byte_offset]);
+    let column = unicode_segmentation::UnicodeSegmentation::graphemes(start.as_ref(), true).count();
+    let style = severity_style(msg.severity, palette);
+    writeln!(
+        handle,
+        "{}: {}",
+        style.paint(msg.severity),
+        palette.strong.paint(forbidden_summary(msg))
+    )?;
+    print_long_context(
+        &mut handle,
+        &msg.context,
+        &line,
+        column,
+        msg.word,
+        style,
+        palette,
+    )?;
+    if let Some(message) = msg.message {
+        writeln!(handle, "  = {}", message)?;
+    }
+
+    Ok(())
+}
+
+fn forbidden_summary(msg: &Forbidden) -> String {
+    if msg.replacement.is_empty() {
+        format!("`{}` is forbidden", msg.word)
+    } else {
+        format!(
+            "`{}` is forbidden, use {}",
+            msg.word,
+            itertools::join(msg.replacement.iter().map(|s| format!("`{}`", s)), ", ")
+        )
+    }
+}
+
+/// Only call out findings that aren't errors, keeping the common case terse.
+fn brief_severity(severity: typos_cli::config::Severity) -> String {
+    match severity {
+        typos_cli::config::Severity::Error => String::new(),
+        _ => format!("{}: ", severity),
+    }
+}
+
+fn severity_style(severity: typos_cli::config::Severity, palette: Palette) -> yansi::Style {
+    match severity {
+        typos_cli::config::Severity::Error => palette.error,
+        typos_cli::config::Severity::Warning => palette.warn,
+        typos_cli::config::Severity::Info => palette.info,
+    }
+}
+
+fn print_long_context(
+    handle: &mut dyn Write,
+    context: &Option<Context<'_>>,
+    line: &str,
+    column: usize,
+    typo: &str,
+    hl_style: yansi::Style,
+    palette: Palette,
+) -> Result<(), std::io::Error> {
     let divider = ":";
     writeln!(
         handle,
         "  --> {}{}{}",
-        palette.info.paint(context_display(&msg.context)),
+        palette.info.paint(context_display(context)),
         palette.info.paint(divider),
         palette.info.paint(column)
     )?;
 
-    if let Some(Context::File(context)) = &msg.context {
+    if let Some(Context::File(context)) = context {
         let line_num = context.line_num.to_string();
         let line_indent: String = itertools::repeat_n(" ", line_num.len()).collect();
 
         let hl_indent: String = itertools::repeat_n(" ", column).collect();
-        let hl: String = itertools::repeat_n("^", msg.typo.len()).collect();
+        // Only the first line of a multi-line typo is shown
+        let hl_len = typo.lines().next().unwrap_or_default().len();
+        let hl: String = itertools::repeat_n("^", hl_len).collect();
 
         writeln!(handle, "{} |", line_indent)?;
         writeln!(
@@ -236,7 +389,7 @@ fn print_long_correction(msg: &Typo, palette: Palette) -> Result<(), std::io::Er
             "{} | {}{}",
             line_indent,
             hl_indent,
-            palette.error.paint(hl)
+            hl_style.paint(hl)
         )?;
         writeln!(handle, "{} |", line_indent)?;
     }
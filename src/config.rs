@@ -4,6 +4,10 @@ use std::collections::HashMap;
 #[serde(deny_unknown_fields, default)]
 #[serde(rename_all = "kebab-case")]
 pub struct Config {
+    /// Config files to layer this one over, in order, relative to this file.  Resolved by
+    /// `from_file`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub extends: Vec<std::path::PathBuf>,
     pub files: Walk,
     pub default: EngineConfig,
     #[serde(rename = "type")]
@@ -13,21 +17,105 @@ pub struct Config {
 }
 
 impl Config {
+    /// Load the first of `SUPPORTED_FILE_NAMES` in `cwd` with a config.
     pub fn from_dir(cwd: &std::path::Path) -> Result<Option<Self>, anyhow::Error> {
-        let config = if let Some(path) =
-            find_project_file(cwd, &["typos.toml", "_typos.toml", ".typos.toml"])
-        {
-            log::debug!("Loading {}", path.display());
-            Some(Self::from_file(&path)?)
+        for name in SUPPORTED_FILE_NAMES {
+            let path = cwd.join(name);
+            if !path.exists() {
+                continue;
+            }
+            if let Some(config) = Self::from_file_extending(&path, &mut Vec::new())? {
+                log::debug!("Loading {}", path.display());
+                return Ok(Some(config));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Load the user's config from `user_config_path`, if present.
+    ///
+    /// An empty `TYPOS_USER_CONFIG` disables the user config.
+    pub fn from_user_file() -> Result<Option<Self>, anyhow::Error> {
+        let path = if let Some(path) = std::env::var_os(USER_CONFIG_ENV) {
+            if path.is_empty() {
+                return Ok(None);
+            }
+            std::path::PathBuf::from(path)
         } else {
-            None
+            match user_config_path() {
+                Some(path) if path.exists() => path,
+                _ => return Ok(None),
+            }
         };
-        Ok(config)
+        log::debug!("Loading {}", path.display());
+        let config = Self::from_file(&path)
+            .map_err(|err| anyhow::format_err!("Could not load `{}`: {}", path.display(), err))?;
+        Ok(Some(config))
     }
 
     pub fn from_file(path: &std::path::Path) -> Result<Self, anyhow::Error> {
-        let s = std::fs::read_to_string(path)?;
-        Self::from_toml(&s)
+        let config = Self::from_file_extending(path, &mut Vec::new())?;
+        Ok(config.unwrap_or_default())
+    }
+
+    /// Load `path`, layered over the configs it `extends`.
+    ///
+    /// `chain` is the files extending `path`, outermost first.  `None` for a `Cargo.toml` or
+    /// `pyproject.toml` without a `typos` table.
+    fn from_file_extending(
+        path: &std::path::Path,
+        chain: &mut Vec<std::path::PathBuf>,
+    ) -> Result<Option<Self>, anyhow::Error> {
+        let load = || -> Result<Option<Self>, anyhow::Error> {
+            let s = std::fs::read_to_string(path)?;
+            let file_name = path.file_name().and_then(|n| n.to_str());
+            let config = match file_name {
+                Some("Cargo.toml") => Self::from_cargo_toml(&s)?,
+                Some("pyproject.toml") => Self::from_pyproject_toml(&s)?,
+                _ => Some(Self::from_toml(&s)?),
+            };
+            Ok(config.map(|mut config| {
+                if let Some(base) = path.parent() {
+                    config.rebase(base);
+                }
+                config
+            }))
+        };
+        let config = if chain.is_empty() {
+            load()?
+        } else {
+            load().map_err(|err| {
+                anyhow::format_err!(
+                    "Could not load `{}`, extended by {}: {}",
+                    path.display(),
+                    display_chain(chain),
+                    err
+                )
+            })?
+        };
+        let mut config = match config {
+            Some(config) if !config.extends.is_empty() => config,
+            config => return Ok(config),
+        };
+
+        let id = path.canonicalize()?;
+        if chain.contains(&id) {
+            chain.push(id);
+            anyhow::bail!(
+                "Config files extend each other in a cycle: {}",
+                display_chain(chain)
+            );
+        }
+        chain.push(id);
+        let mut extended = Self::default();
+        for base_path in std::mem::take(&mut config.extends) {
+            if let Some(base) = Self::from_file_extending(&base_path, chain)? {
+                extended.update(&base);
+            }
+        }
+        chain.pop();
+        extended.update(&config);
+        Ok(Some(extended))
     }
 
     pub fn from_toml(data: &str) -> Result<Self, anyhow::Error> {
@@ -35,8 +123,25 @@ impl Config {
         Ok(content)
     }
 
+    /// The `package.metadata.typos` table of a `Cargo.toml`, falling back to
+    /// `workspace.metadata.typos`.
+    pub fn from_cargo_toml(data: &str) -> Result<Option<Self>, anyhow::Error> {
+        let CargoManifest { package, workspace } = toml::from_str(data)?;
+        let config = package
+            .and_then(|p| p.metadata.typos)
+            .or_else(|| workspace.and_then(|w| w.metadata.typos));
+        Ok(config)
+    }
+
+    /// The `tool.typos` table of a `pyproject.toml`.
+    pub fn from_pyproject_toml(data: &str) -> Result<Option<Self>, anyhow::Error> {
+        let project: PyProject = toml::from_str(data)?;
+        Ok(project.tool.typos)
+    }
+
     pub fn from_defaults() -> Self {
         Self {
+            extends: Default::default(),
             files: Walk::from_defaults(),
             default: EngineConfig::from_defaults(),
             type_: Default::default(),
@@ -44,6 +149,28 @@ impl Config {
         }
     }
 
+    /// Settings built into `typos` that config files are layered on top of.
+    pub fn builtin() -> Self {
+        let c_family = TypeEngineConfig {
+            engine: EngineConfig {
+                tokenizer: Some(TokenizerConfig {
+                    c_escapes: Some(true),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let type_ = C_FAMILY_TYPES
+            .iter()
+            .map(|type_name| (kstring::KString::from_static(type_name), c_family.clone()))
+            .collect();
+        Self {
+            type_,
+            ..Default::default()
+        }
+    }
+
     pub fn update(&mut self, source: &Config) {
         self.files.update(&source.files);
         self.default.update(&source.default);
@@ -55,8 +182,26 @@ impl Config {
         }
         self.overrides.update(&source.overrides);
     }
+
+    /// Resolve relative paths against `base`, the directory of the config file.
+    pub fn rebase(&mut self, base: &std::path::Path) {
+        for path in self.extends.iter_mut() {
+            *path = base.join(&path);
+        }
+        self.default.rebase(base);
+        for engine in self.type_.values_mut() {
+            engine.engine.rebase(base);
+        }
+        self.overrides.rebase(base);
+    }
 }
 
+/// File types whose string literals use C-style escape sequences.
+const C_FAMILY_TYPES: &[&str] = &[
+    "c", "cpp", "cs", "csharp", "go", "h", "java", "js", "kotlin", "objc", "objcpp", "py", "rust",
+    "scala", "swift", "ts",
+];
+
 #[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[serde(deny_unknown_fields, default)]
 #[serde(rename_all = "kebab-case")]
@@ -146,8 +291,9 @@ impl Walk {
     }
 }
 
+// `deny_unknown_fields` doesn't work with `flatten`, leaving it to `EngineConfig`
 #[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
-#[serde(deny_unknown_fields, default)]
+#[serde(default)]
 #[serde(rename_all = "kebab-case")]
 pub struct TypeEngineConfig {
     pub extend_glob: Vec<kstring::KString>,
@@ -172,10 +318,22 @@ pub struct EngineConfig {
     pub check_filename: Option<bool>,
     /// Verifying spelling in files.
     pub check_file: Option<bool>,
+    /// Which parts of a file to verify.
+    pub check_mode: Option<CheckMode>,
+    /// Report words repeated across whitespace, like "the the".
+    pub check_doubled_words: Option<bool>,
+    /// Report words missing from the valid-word list, with suggestions.
+    pub check_unknown_words: Option<bool>,
+    /// How serious typos are, with only errors failing the run.
+    pub severity: Option<Severity>,
     #[serde(flatten)]
     pub tokenizer: Option<TokenizerConfig>,
     #[serde(flatten)]
     pub dict: Option<DictConfig>,
+    /// Severities for specific typos, taking precedence over `severity`.
+    pub word_severity: HashMap<kstring::KString, Severity>,
+    /// Words to report with an explanation, whatever their spelling.
+    pub forbid: HashMap<kstring::KString, ForbiddenWord>,
 }
 
 impl EngineConfig {
@@ -185,12 +343,18 @@ impl EngineConfig {
             binary: Some(empty.binary()),
             check_filename: Some(empty.check_filename()),
             check_file: Some(empty.check_file()),
+            check_mode: Some(empty.check_mode()),
+            check_doubled_words: Some(empty.check_doubled_words()),
+            check_unknown_words: Some(empty.check_unknown_words()),
+            severity: Some(empty.severity()),
             tokenizer: Some(
                 empty
                     .tokenizer
                     .unwrap_or_else(TokenizerConfig::from_defaults),
             ),
             dict: Some(empty.dict.unwrap_or_else(DictConfig::from_defaults)),
+            word_severity: Default::default(),
+            forbid: Default::default(),
         }
     }
 
@@ -204,6 +368,27 @@ impl EngineConfig {
         if let Some(source) = source.check_file {
             self.check_file = Some(source);
         }
+        if let Some(source) = source.check_mode {
+            self.check_mode = Some(source);
+        }
+        if let Some(source) = source.check_doubled_words {
+            self.check_doubled_words = Some(source);
+        }
+        if let Some(source) = source.check_unknown_words {
+            self.check_unknown_words = Some(source);
+        }
+        if let Some(source) = source.severity {
+            self.severity = Some(source);
+        }
+        self.word_severity.extend(
+            source
+                .word_severity
+                .iter()
+                .map(|(key, value)| (key.clone(), *value)),
+        );
+        for (word, source) in source.forbid.iter() {
+            self.forbid.entry(word.clone()).or_default().update(source);
+        }
         if let Some(source) = source.tokenizer.as_ref() {
             let mut tokenizer = None;
             std::mem::swap(&mut tokenizer, &mut self.tokenizer);
@@ -222,6 +407,12 @@ impl EngineConfig {
         }
     }
 
+    pub fn rebase(&mut self, base: &std::path::Path) {
+        if let Some(dict) = self.dict.as_mut() {
+            dict.rebase(base);
+        }
+    }
+
     pub fn binary(&self) -> bool {
         self.binary.unwrap_or(false)
     }
@@ -233,6 +424,80 @@ impl EngineConfig {
     pub fn check_file(&self) -> bool {
         self.check_file.unwrap_or(true)
     }
+
+    pub fn check_mode(&self) -> CheckMode {
+        self.check_mode.unwrap_or_default()
+    }
+
+    pub fn check_doubled_words(&self) -> bool {
+        self.check_doubled_words.unwrap_or(false)
+    }
+
+    pub fn check_unknown_words(&self) -> bool {
+        self.check_unknown_words.unwrap_or(false)
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.severity.unwrap_or_default()
+    }
+
+    pub fn word_severity(&self) -> Box<dyn Iterator<Item = (&str, Severity)> + '_> {
+        Box::new(self.word_severity.iter().map(|(k, v)| (k.as_str(), *v)))
+    }
+
+    pub fn forbid(&self) -> Box<dyn Iterator<Item = (&str, &ForbiddenWord)> + '_> {
+        Box::new(self.forbid.iter().map(|(k, v)| (k.as_str(), v)))
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, default)]
+#[serde(rename_all = "kebab-case")]
+pub struct ForbiddenWord {
+    /// What to use instead, fixed automatically when there is only one.
+    pub replacement: Option<Replacement>,
+    /// Why the word is forbidden.
+    pub message: Option<String>,
+    /// Defaults to the file's `severity`.
+    pub severity: Option<Severity>,
+}
+
+impl ForbiddenWord {
+    pub fn update(&mut self, source: &ForbiddenWord) {
+        if let Some(source) = source.replacement.as_ref() {
+            self.replacement = Some(source.clone());
+        }
+        if let Some(source) = source.message.as_ref() {
+            self.message = Some(source.clone());
+        }
+        if let Some(source) = source.severity {
+            self.severity = Some(source);
+        }
+    }
+
+    pub fn replacement(&self) -> &[kstring::KString] {
+        match self.replacement.as_ref() {
+            Some(Replacement::One(replacement)) => std::slice::from_ref(replacement),
+            Some(Replacement::Many(replacements)) => replacements,
+            None => &[],
+        }
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    pub fn severity(&self) -> Option<Severity> {
+        self.severity
+    }
+}
+
+/// Either a single replacement or a list of them.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum Replacement {
+    One(kstring::KString),
+    Many(Vec<kstring::KString>),
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -245,6 +510,16 @@ pub struct TokenizerConfig {
     pub ignore_hex: Option<bool>,
     /// Allow identifiers to start with digits, in addition to letters.
     pub identifier_leading_digits: Option<bool>,
+    /// Skip content matching any of these regexes before looking for identifiers.
+    pub extend_ignore_re: Vec<String>,
+    /// Treat C-style escape sequences (`\n`, `\x41`, etc) as separators.
+    pub c_escapes: Option<bool>,
+    /// Treat printf-style placeholders (`%d`, `%(name)s`, etc) as separators.
+    pub printf_placeholders: Option<bool>,
+    /// Treat brace placeholders (`{}`, `{0}`, `{:>8}`, etc) as separators.
+    pub brace_placeholders: Option<bool>,
+    /// Split lowercase words without boundaries, like `recievedata`, to check the words inside.
+    pub segment_words: Option<bool>,
 }
 
 impl TokenizerConfig {
@@ -254,6 +529,11 @@ impl TokenizerConfig {
             unicode: Some(empty.unicode()),
             ignore_hex: Some(empty.ignore_hex()),
             identifier_leading_digits: Some(empty.identifier_leading_digits()),
+            extend_ignore_re: Default::default(),
+            c_escapes: Some(empty.c_escapes()),
+            printf_placeholders: Some(empty.printf_placeholders()),
+            brace_placeholders: Some(empty.brace_placeholders()),
+            segment_words: Some(empty.segment_words()),
         }
     }
 
@@ -267,6 +547,20 @@ impl TokenizerConfig {
         if let Some(source) = source.identifier_leading_digits {
             self.identifier_leading_digits = Some(source);
         }
+        self.extend_ignore_re
+            .extend(source.extend_ignore_re.iter().cloned());
+        if let Some(source) = source.c_escapes {
+            self.c_escapes = Some(source);
+        }
+        if let Some(source) = source.printf_placeholders {
+            self.printf_placeholders = Some(source);
+        }
+        if let Some(source) = source.brace_placeholders {
+            self.brace_placeholders = Some(source);
+        }
+        if let Some(source) = source.segment_words {
+            self.segment_words = Some(source);
+        }
     }
 
     pub fn unicode(&self) -> bool {
@@ -280,6 +574,26 @@ impl TokenizerConfig {
     pub fn identifier_leading_digits(&self) -> bool {
         self.identifier_leading_digits.unwrap_or(false)
     }
+
+    pub fn extend_ignore_re(&self) -> &[String] {
+        &self.extend_ignore_re
+    }
+
+    pub fn c_escapes(&self) -> bool {
+        self.c_escapes.unwrap_or(false)
+    }
+
+    pub fn printf_placeholders(&self) -> bool {
+        self.printf_placeholders.unwrap_or(false)
+    }
+
+    pub fn brace_placeholders(&self) -> bool {
+        self.brace_placeholders.unwrap_or(false)
+    }
+
+    pub fn segment_words(&self) -> bool {
+        self.segment_words.unwrap_or(false)
+    }
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -287,8 +601,19 @@ impl TokenizerConfig {
 #[serde(rename_all = "kebab-case")]
 pub struct DictConfig {
     pub locale: Option<Locale>,
+    /// Which of the locale's less accepted spellings to report.
+    pub locale_strictness: Option<LocaleStrictness>,
+    /// Bundled dictionaries to look for typos in, with earlier ones taking precedence.
+    pub dictionaries: Option<Vec<BundledDictionary>>,
+    pub extend_ignore_identifiers_re: Vec<String>,
+    pub extend_ignore_words_re: Vec<String>,
+    /// Dictionary files of corrections, in codespell (`typo->fix`) or, for `.csv`, CSV
+    /// (`typo,fix`) format.
+    pub extend_dict_files: Vec<std::path::PathBuf>,
+    // Tables last, since they must serialize after values
     pub extend_identifiers: HashMap<kstring::KString, kstring::KString>,
     pub extend_words: HashMap<kstring::KString, kstring::KString>,
+    pub extend_words_case_sensitive: HashMap<kstring::KString, kstring::KString>,
 }
 
 impl DictConfig {
@@ -296,8 +621,14 @@ impl DictConfig {
         let empty = Self::default();
         Self {
             locale: Some(empty.locale()),
+            locale_strictness: Some(empty.locale_strictness()),
+            dictionaries: Some(empty.dictionaries().to_vec()),
             extend_identifiers: Default::default(),
+            extend_ignore_identifiers_re: Default::default(),
             extend_words: Default::default(),
+            extend_ignore_words_re: Default::default(),
+            extend_words_case_sensitive: Default::default(),
+            extend_dict_files: Default::default(),
         }
     }
 
@@ -305,24 +636,61 @@ impl DictConfig {
         if let Some(source) = source.locale {
             self.locale = Some(source);
         }
+        if let Some(source) = source.locale_strictness {
+            self.locale_strictness = Some(source);
+        }
+        if let Some(source) = source.dictionaries.as_ref() {
+            self.dictionaries = Some(source.clone());
+        }
         self.extend_identifiers.extend(
             source
                 .extend_identifiers
                 .iter()
                 .map(|(key, value)| (key.clone(), value.clone())),
         );
+        self.extend_ignore_identifiers_re
+            .extend(source.extend_ignore_identifiers_re.iter().cloned());
         self.extend_words.extend(
             source
                 .extend_words
                 .iter()
                 .map(|(key, value)| (key.clone(), value.clone())),
         );
+        self.extend_ignore_words_re
+            .extend(source.extend_ignore_words_re.iter().cloned());
+        self.extend_words_case_sensitive.extend(
+            source
+                .extend_words_case_sensitive
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone())),
+        );
+        for path in source.extend_dict_files.iter() {
+            if !self.extend_dict_files.contains(path) {
+                self.extend_dict_files.push(path.clone());
+            }
+        }
+    }
+
+    pub fn rebase(&mut self, base: &std::path::Path) {
+        for path in self.extend_dict_files.iter_mut() {
+            *path = base.join(&path);
+        }
     }
 
     pub fn locale(&self) -> Locale {
         self.locale.unwrap_or_default()
     }
 
+    pub fn locale_strictness(&self) -> LocaleStrictness {
+        self.locale_strictness.unwrap_or_default()
+    }
+
+    pub fn dictionaries(&self) -> &[BundledDictionary] {
+        self.dictionaries
+            .as_deref()
+            .unwrap_or(&[BundledDictionary::Typos])
+    }
+
     pub fn extend_identifiers(&self) -> Box<dyn Iterator<Item = (&str, &str)> + '_> {
         Box::new(
             self.extend_identifiers
@@ -331,6 +699,10 @@ impl DictConfig {
         )
     }
 
+    pub fn extend_ignore_identifiers_re(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        Box::new(self.extend_ignore_identifiers_re.iter().map(|s| s.as_str()))
+    }
+
     pub fn extend_words(&self) -> Box<dyn Iterator<Item = (&str, &str)> + '_> {
         Box::new(
             self.extend_words
@@ -338,17 +710,181 @@ impl DictConfig {
                 .map(|(k, v)| (k.as_str(), v.as_str())),
         )
     }
+
+    pub fn extend_ignore_words_re(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        Box::new(self.extend_ignore_words_re.iter().map(|s| s.as_str()))
+    }
+
+    pub fn extend_words_case_sensitive(&self) -> Box<dyn Iterator<Item = (&str, &str)> + '_> {
+        Box::new(
+            self.extend_words_case_sensitive
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.as_str())),
+        )
+    }
+
+    pub fn extend_dict_files(&self) -> &[std::path::PathBuf] {
+        &self.extend_dict_files
+    }
+}
+
+fn display_chain(chain: &[std::path::PathBuf]) -> String {
+    itertools::join(
+        chain.iter().map(|path| format!("`{}`", path.display())),
+        " -> ",
+    )
+}
+
+/// Environment variable with the path to the user's config, overriding `user_config_path`.
+pub const USER_CONFIG_ENV: &str = "TYPOS_USER_CONFIG";
+
+/// `$XDG_CONFIG_HOME/typos/config.toml`, defaulting to `~/.config/typos/config.toml`.
+pub fn user_config_path() -> Option<std::path::PathBuf> {
+    let config_home = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(std::path::PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME")
+                .or_else(|| std::env::var_os("USERPROFILE"))
+                .filter(|dir| !dir.is_empty())
+                .map(|home| std::path::Path::new(&home).join(".config"))
+        })?;
+    Some(config_home.join("typos").join("config.toml"))
+}
+
+/// Files to look for a config in, in precedence order.
+pub const SUPPORTED_FILE_NAMES: &[&str] = &[
+    "typos.toml",
+    "_typos.toml",
+    ".typos.toml",
+    "Cargo.toml",
+    "pyproject.toml",
+];
+
+#[derive(Default, serde::Deserialize)]
+#[serde(default)]
+struct CargoManifest {
+    package: Option<CargoMetadataTable>,
+    workspace: Option<CargoMetadataTable>,
+}
+
+#[derive(Default, serde::Deserialize)]
+#[serde(default)]
+struct CargoMetadataTable {
+    metadata: ToolTable,
+}
+
+#[derive(Default, serde::Deserialize)]
+#[serde(default)]
+struct PyProject {
+    tool: ToolTable,
+}
+
+#[derive(Default, serde::Deserialize)]
+#[serde(default)]
+struct ToolTable {
+    typos: Option<Config>,
+}
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CheckMode {
+    /// Only comments and string literals, for file types with known syntax
+    Prose,
+    /// Everything
+    #[default]
+    All,
+}
+
+impl CheckMode {
+    pub const fn variants() -> [&'static str; 2] {
+        ["prose", "all"]
+    }
+}
+
+impl std::str::FromStr for CheckMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "prose" => Ok(CheckMode::Prose),
+            "all" => Ok(CheckMode::All),
+            _ => Err("valid values: prose, all".to_owned()),
+        }
+    }
 }
 
-fn find_project_file(dir: &std::path::Path, names: &[&str]) -> Option<std::path::PathBuf> {
-    let mut file_path = dir.join("placeholder");
-    for name in names {
-        file_path.set_file_name(name);
-        if file_path.exists() {
-            return Some(file_path);
+impl std::fmt::Display for CheckMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            CheckMode::Prose => write!(f, "prose"),
+            CheckMode::All => write!(f, "all"),
         }
     }
-    None
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BundledDictionary {
+    Typos,
+    Codespell,
+    Misspell,
+    Wikipedia,
+}
+
+impl BundledDictionary {
+    pub const fn name(self) -> &'static str {
+        match self {
+            BundledDictionary::Typos => "typos",
+            BundledDictionary::Codespell => "codespell",
+            BundledDictionary::Misspell => "misspell",
+            BundledDictionary::Wikipedia => "wikipedia",
+        }
+    }
+
+    /// The cargo feature that compiles in the dictionary.
+    pub const fn feature(self) -> &'static str {
+        match self {
+            BundledDictionary::Typos => "dict",
+            BundledDictionary::Codespell => "codespell",
+            BundledDictionary::Misspell => "misspell",
+            BundledDictionary::Wikipedia => "wikipedia",
+        }
+    }
+
+    /// Whether the dictionary was compiled in, through its cargo feature.
+    pub const fn is_available(self) -> bool {
+        match self {
+            BundledDictionary::Typos => cfg!(feature = "dict"),
+            BundledDictionary::Codespell => cfg!(feature = "codespell"),
+            BundledDictionary::Misspell => cfg!(feature = "misspell"),
+            BundledDictionary::Wikipedia => cfg!(feature = "wikipedia"),
+        }
+    }
+
+    pub const fn variants() -> [&'static str; 4] {
+        ["typos", "codespell", "misspell", "wikipedia"]
+    }
+}
+
+impl std::str::FromStr for BundledDictionary {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "typos" => Ok(BundledDictionary::Typos),
+            "codespell" => Ok(BundledDictionary::Codespell),
+            "misspell" => Ok(BundledDictionary::Misspell),
+            "wikipedia" => Ok(BundledDictionary::Wikipedia),
+            _ => Err("valid values: typos, codespell, misspell, wikipedia".to_owned()),
+        }
+    }
+}
+
+impl std::fmt::Display for BundledDictionary {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -357,6 +893,7 @@ pub enum Locale {
     En,
     EnUs,
     EnGb,
+    EnGbOxendict,
     EnCa,
     EnAu,
 }
@@ -367,13 +904,14 @@ impl Locale {
             Locale::En => None,
             Locale::EnUs => Some(varcon_core::Category::American),
             Locale::EnGb => Some(varcon_core::Category::BritishIse),
+            Locale::EnGbOxendict => Some(varcon_core::Category::BritishIze),
             Locale::EnCa => Some(varcon_core::Category::Canadian),
             Locale::EnAu => Some(varcon_core::Category::Australian),
         }
     }
 
-    pub const fn variants() -> [&'static str; 5] {
-        ["en", "en-us", "en-gb", "en-ca", "en-au"]
+    pub const fn variants() -> [&'static str; 6] {
+        ["en", "en-us", "en-gb", "en-gb-oxendict", "en-ca", "en-au"]
     }
 }
 
@@ -391,9 +929,10 @@ impl std::str::FromStr for Locale {
             "en" => Ok(Locale::En),
             "en-us" => Ok(Locale::EnUs),
             "en-gb" => Ok(Locale::EnGb),
+            "en-gb-oxendict" => Ok(Locale::EnGbOxendict),
             "en-ca" => Ok(Locale::EnCa),
             "en-au" => Ok(Locale::EnAu),
-            _ => Err("valid values: en, en-us, en-gb, en-ca, en-au".to_owned()),
+            _ => Err("valid values: en, en-us, en-gb, en-gb-oxendict, en-ca, en-au".to_owned()),
         }
     }
 }
@@ -404,12 +943,79 @@ impl std::fmt::Display for Locale {
             Locale::En => write!(f, "en"),
             Locale::EnUs => write!(f, "en-us"),
             Locale::EnGb => write!(f, "en-gb"),
+            Locale::EnGbOxendict => write!(f, "en-gb-oxendict"),
             Locale::EnCa => write!(f, "en-ca"),
             Locale::EnAu => write!(f, "en-au"),
         }
     }
 }
 
+/// Which spellings, by how accepted they are in the locale, to report.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LocaleStrictness {
+    /// Accept every spelling listed for the dialect, even improper ones
+    #[default]
+    Permissive,
+    /// Only report improper spellings
+    Lenient,
+    /// Also report questionable spellings
+    Standard,
+    /// Also report variant and seldom used spellings
+    Strict,
+}
+
+impl LocaleStrictness {
+    pub const fn variants() -> [&'static str; 4] {
+        ["permissive", "lenient", "standard", "strict"]
+    }
+}
+
+impl std::str::FromStr for LocaleStrictness {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "permissive" => Ok(LocaleStrictness::Permissive),
+            "lenient" => Ok(LocaleStrictness::Lenient),
+            "standard" => Ok(LocaleStrictness::Standard),
+            "strict" => Ok(LocaleStrictness::Strict),
+            _ => Err("valid values: permissive, lenient, standard, strict".to_owned()),
+        }
+    }
+}
+
+impl std::fmt::Display for LocaleStrictness {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            LocaleStrictness::Permissive => write!(f, "permissive"),
+            LocaleStrictness::Lenient => write!(f, "lenient"),
+            LocaleStrictness::Standard => write!(f, "standard"),
+            LocaleStrictness::Strict => write!(f, "strict"),
+        }
+    }
+}
+
+/// How serious a finding is, with only errors failing the run.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Severity {
+    #[default]
+    Error,
+    Warning,
+    Info,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Info => write!(f, "info"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -447,6 +1053,28 @@ mod test {
         assert_eq!(actual, defaulted);
     }
 
+    #[test]
+    fn test_rebase_dict_files() {
+        let mut actual = Config::from_toml(
+            r#"
+[default]
+extend-dict-files = ["words.txt", "../shared.txt", "/abs/words.csv"]
+"#,
+        )
+        .unwrap();
+        actual.rebase(std::path::Path::new("/root"));
+
+        let default = actual.default.dict.as_ref().unwrap();
+        assert_eq!(
+            default.extend_dict_files(),
+            [
+                std::path::PathBuf::from("/root/words.txt"),
+                std::path::PathBuf::from("/root/../shared.txt"),
+                std::path::PathBuf::from("/abs/words.csv")
+            ]
+        );
+    }
+
     #[test]
     fn test_extend_glob_updates() {
         let null = TypeEngineConfig::default();
@@ -478,4 +1106,138 @@ mod test {
         let expected: Vec<kstring::KString> = vec!["*.foo".into(), "*.bar".into()];
         assert_eq!(actual.extend_glob, expected);
     }
+
+    #[test]
+    fn test_forbid_updates_fields() {
+        let base: Config = Config::from_toml(
+            r#"
+[default.forbid]
+master = { replacement = ["main", "primary"], message = "Use inclusive language" }
+"#,
+        )
+        .unwrap();
+        let extended: Config = Config::from_toml(
+            r#"
+[default.forbid.master]
+replacement = "main"
+severity = "warning"
+"#,
+        )
+        .unwrap();
+
+        let mut actual = base;
+        actual.update(&extended);
+
+        let forbid = &actual.default.forbid["master"];
+        let expected: &[kstring::KString] = &["main".into()];
+        assert_eq!(forbid.replacement(), expected);
+        assert_eq!(forbid.message(), Some("Use inclusive language"));
+        assert_eq!(forbid.severity(), Some(Severity::Warning));
+    }
+
+    #[test]
+    fn test_type_from_toml() {
+        let config = Config::from_toml(
+            r#"
+[type.rust]
+check-file = false
+locale = "en-gb"
+
+[type.rust.forbid.master]
+severity = "info"
+"#,
+        )
+        .unwrap();
+
+        let engine = &config.type_["rust"].engine;
+        assert_eq!(engine.check_file, Some(false));
+        assert_eq!(engine.dict.as_ref().unwrap().locale, Some(Locale::EnGb));
+        assert_eq!(engine.forbid["master"].severity, Some(Severity::Info));
+
+        assert!(Config::from_toml("[type.rust]\nbogus = true\n").is_err());
+    }
+
+    #[test]
+    fn test_from_cargo_toml() {
+        let config = Config::from_cargo_toml(
+            r#"
+[package]
+name = "foo"
+
+[package.metadata.typos]
+default.check-filename = false
+
+[workspace.metadata.typos]
+default.check-file = false
+"#,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(config.default.check_filename, Some(false));
+        assert_eq!(config.default.check_file, None);
+
+        let config = Config::from_cargo_toml(
+            "[workspace.metadata.typos]
+",
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(config, Config::default());
+
+        let config = Config::from_cargo_toml(
+            "[package]
+name = \"foo\"
+",
+        )
+        .unwrap();
+        assert_eq!(config, None);
+    }
+
+    #[test]
+    fn test_from_pyproject_toml() {
+        let config = Config::from_pyproject_toml(
+            r#"
+[project]
+name = "foo"
+
+[tool.typos]
+default.check-file = false
+"#,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(config.default.check_file, Some(false));
+
+        let config = Config::from_pyproject_toml(
+            "[tool.black]
+",
+        )
+        .unwrap();
+        assert_eq!(config, None);
+    }
+
+    #[test]
+    fn test_to_toml() {
+        let mut config = Config::from_defaults();
+        config.update(
+            &Config::from_toml(
+                r#"
+[default]
+extend-ignore-words-re = ["^x"]
+extend-dict-files = ["typos.dict"]
+
+[default.extend-words]
+teh = "teh"
+
+[default.forbid]
+whitelist = { replacement = "allowlist" }
+"#,
+            )
+            .unwrap(),
+        );
+
+        let data = toml::to_string_pretty(&config).unwrap();
+        let actual = Config::from_toml(&data).unwrap();
+        assert_eq!(actual, config);
+    }
 }
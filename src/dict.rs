@@ -6,18 +6,42 @@ use unicase::UniCase;
 use typos::tokens::Case;
 use typos::Status;
 
-#[derive(Default)]
+use crate::config::BundledDictionary;
+use crate::config::LocaleStrictness;
+
 pub struct BuiltIn {
     locale: Option<varcon_core::Category>,
+    locale_strictness: LocaleStrictness,
+    dictionaries: Cow<'static, [BundledDictionary]>,
+}
+
+impl Default for BuiltIn {
+    fn default() -> Self {
+        Self::new(crate::config::Locale::default())
+    }
 }
 
 impl BuiltIn {
     pub const fn new(locale: crate::config::Locale) -> Self {
         Self {
             locale: locale.category(),
+            locale_strictness: LocaleStrictness::Permissive,
+            dictionaries: Cow::Borrowed(&[BundledDictionary::Typos]),
         }
     }
 
+    /// Which of the locale's less accepted spellings to report.
+    pub fn locale_strictness(&mut self, locale_strictness: LocaleStrictness) -> &mut Self {
+        self.locale_strictness = locale_strictness;
+        self
+    }
+
+    /// Bundled dictionaries to look for typos in, with earlier ones taking precedence.
+    pub fn dictionaries(&mut self, dictionaries: &[BundledDictionary]) -> &mut Self {
+        self.dictionaries = Cow::Owned(dictionaries.to_vec());
+        self
+    }
+
     pub fn correct_ident<'s, 'w>(
         &'s self,
         _ident: typos::tokens::Identifier<'w>,
@@ -29,136 +53,280 @@ impl BuiltIn {
         &'s self,
         word_token: typos::tokens::Word<'w>,
     ) -> Option<Status<'s>> {
+        self.correct_word_with_details(word_token)
+            .map(|(status, _)| status)
+    }
+
+    pub fn is_valid_word(&self, word_token: typos::tokens::Word<'_>) -> bool {
+        let word = word_token.token();
+        let valid = if word_token.case() == Case::Lower {
+            self.is_valid_with_words(word)
+        } else {
+            self.is_valid_with_words(&word.to_lowercase())
+        };
+        valid || self.is_valid_with_vars(unicase::UniCase::new(word))
+    }
+}
+
+impl BuiltIn {
+    pub fn correct_word_with_details(
+        &self,
+        word_token: typos::tokens::Word<'_>,
+    ) -> Option<(Status<'static>, Vec<typos::CorrectionDetails<'static>>)> {
         if word_token.case() == typos::tokens::Case::None {
             return None;
         }
 
         let word = word_token.token();
         let word_case = unicase::UniCase::new(word);
-        let mut corrections = if let Some(corrections) = self.correct_with_dict(word_case) {
-            if corrections.is_empty() {
-                Status::Invalid
+        let (mut status, details) =
+            if let Some((dictionary, corrections)) = self.find_in_dicts(word_case) {
+                if corrections.is_empty() {
+                    (Status::Invalid, Vec::new())
+                } else {
+                    into_status(self.chain_with_vars(dictionary, corrections))
+                }
             } else {
-                self.chain_with_vars(corrections)
-            }
-        } else {
-            self.correct_with_vars(word_case)?
-        };
-        corrections
+                let corrections = self.correct_with_vars(word_case)?;
+                if corrections.is_empty() {
+                    (Status::Valid, Vec::new())
+                } else {
+                    into_status(corrections)
+                }
+            };
+        status
             .corrections_mut()
-            .for_each(|mut s| case_correct(&mut s, word_token.case()));
-        Some(corrections)
+            .for_each(|s| case_correct(s, word_token.case()));
+        Some((status, details))
+    }
+
+    fn find_in_dicts(
+        &self,
+        word: unicase::UniCase<&str>,
+    ) -> Option<(BundledDictionary, &'static [&'static str])> {
+        self.dictionaries.iter().find_map(|dictionary| {
+            find_in_dict(*dictionary, word).map(|corrections| (*dictionary, corrections))
+        })
     }
 }
 
-#[cfg(feature = "dict")]
+fn find_in_dict(
+    dictionary: BundledDictionary,
+    word: unicase::UniCase<&str>,
+) -> Option<&'static [&'static str]> {
+    match dictionary {
+        #[cfg(feature = "dict")]
+        BundledDictionary::Typos => typos_dict::WORD_TRIE.find(&word).copied(),
+        #[cfg(feature = "codespell")]
+        BundledDictionary::Codespell => codespell_dict::WORD_DICTIONARY.find(&word).copied(),
+        #[cfg(feature = "misspell")]
+        BundledDictionary::Misspell => misspell_dict::MAIN_DICTIONARY.find(&word).copied(),
+        #[cfg(feature = "wikipedia")]
+        BundledDictionary::Wikipedia => wikipedia_dict::WORD_DICTIONARY.find(&word).copied(),
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }
+}
+
+/// Corrections paired with their details, to keep them in the same order.
+type Corrections = Vec<(&'static str, typos::CorrectionDetails<'static>)>;
+
+fn into_status(
+    corrections: Corrections,
+) -> (Status<'static>, Vec<typos::CorrectionDetails<'static>>) {
+    // Ambiguous corrections split the confidence between them
+    let count = corrections.len() as f32;
+    let (corrections, details) = corrections
+        .into_iter()
+        .map(|(correction, mut details)| {
+            details.confidence /= count;
+            (Cow::Borrowed(correction), details)
+        })
+        .unzip();
+    (Status::Corrections(corrections), details)
+}
+
+fn dictionary_details(dictionary: BundledDictionary) -> typos::CorrectionDetails<'static> {
+    let confidence = match dictionary {
+        // Curated for source code
+        BundledDictionary::Typos => 0.9,
+        BundledDictionary::Codespell
+        | BundledDictionary::Misspell
+        | BundledDictionary::Wikipedia => 0.7,
+    };
+    typos::CorrectionDetails {
+        source: Some(Cow::Borrowed(dictionary.name())),
+        tag: None,
+        confidence,
+    }
+}
+
+#[cfg(feature = "words")]
 impl BuiltIn {
-    // Not using `Status` to avoid the allocations
-    fn correct_with_dict(&self, word: unicase::UniCase<&str>) -> Option<&'static [&'static str]> {
-        typos_dict::WORD_TRIE.find(&word).copied()
+    fn is_valid_with_words(&self, word: &str) -> bool {
+        typos_words::VALID_WORDS.binary_search(&word).is_ok()
     }
 }
 
-#[cfg(not(feature = "dict"))]
+#[cfg(not(feature = "words"))]
 impl BuiltIn {
-    fn correct_with_dict(&self, _word: unicase::UniCase<&str>) -> Option<&'static [&'static str]> {
-        None
+    fn is_valid_with_words(&self, _word: &str) -> bool {
+        false
     }
 }
 
 #[cfg(feature = "vars")]
 impl BuiltIn {
-    fn chain_with_vars(&self, corrections: &'static [&'static str]) -> Status<'static> {
+    fn chain_with_vars(
+        &self,
+        dictionary: BundledDictionary,
+        corrections: &'static [&'static str],
+    ) -> Corrections {
+        let details = dictionary_details(dictionary);
         if self.is_vars_enabled() {
-            let mut chained: Vec<_> = corrections
+            let mut chained: Corrections = corrections
                 .iter()
                 .flat_map(|c| match self.correct_with_vars(unicase::UniCase::new(c)) {
-                    Some(Status::Valid) | None => vec![Cow::Borrowed(*c)],
-                    Some(Status::Corrections(vars)) => vars,
-                    Some(Status::Invalid) => {
-                        unreachable!("correct_with_vars should always have valid suggestions")
-                    }
+                    Some(vars) if !vars.is_empty() => vars
+                        .into_iter()
+                        .map(|(var, var_details)| {
+                            // Only as trustworthy as the least trustworthy step
+                            let details = typos::CorrectionDetails {
+                                tag: var_details.tag,
+                                confidence: details.confidence.min(var_details.confidence),
+                                ..details.clone()
+                            };
+                            (var, details)
+                        })
+                        .collect(),
+                    _ => vec![(*c, details.clone())],
                 })
                 .collect();
             if chained.len() != 1 {
-                chained.sort_unstable();
-                chained.dedup();
+                chained.sort_unstable_by_key(|(c, _)| *c);
+                chained.dedup_by_key(|(c, _)| *c);
             }
             debug_assert!(!chained.is_empty());
-            Status::Corrections(chained)
+            chained
         } else {
-            Status::Corrections(corrections.iter().map(|c| Cow::Borrowed(*c)).collect())
+            corrections.iter().map(|c| (*c, details.clone())).collect()
         }
     }
 
-    fn correct_with_vars(&self, word: unicase::UniCase<&str>) -> Option<Status<'static>> {
+    /// Empty if the word is valid for the locale.
+    fn correct_with_vars(&self, word: unicase::UniCase<&str>) -> Option<Corrections> {
         if self.is_vars_enabled() {
             typos_vars::VARS_TRIE
                 .find(&word)
-                .map(|variants| self.select_variant(variants))
+                .map(|variants| self.select_variant(word, variants))
         } else {
             None
         }
     }
 
+    fn is_valid_with_vars(&self, word: unicase::UniCase<&str>) -> bool {
+        // Regardless of locale, as the word would be corrected if it didn't belong to it
+        typos_vars::VARS_TRIE
+            .find(&word)
+            .map(|variants| !self.valid_categories(&variants[0].0).is_empty())
+            .unwrap_or(false)
+    }
+
     fn is_vars_enabled(&self) -> bool {
         #![allow(clippy::assertions_on_constants)]
         debug_assert!(typos_vars::NO_INVALID);
         self.locale.is_some()
     }
 
+    fn valid_categories(
+        &self,
+        categories: &typos_vars::CategoriesByTag,
+    ) -> typos_vars::CategorySet {
+        let max_tag = match self.locale_strictness {
+            LocaleStrictness::Permissive => typos_vars::Tag::Improper,
+            LocaleStrictness::Lenient => typos_vars::Tag::Possible,
+            LocaleStrictness::Standard => typos_vars::Tag::Seldom,
+            LocaleStrictness::Strict => typos_vars::Tag::Eq,
+        };
+        typos_vars::valid_categories(categories, max_tag)
+    }
+
     fn select_variant(
         &self,
-        vars: &'static [(u8, &'static typos_vars::VariantsMap)],
-    ) -> Status<'static> {
+        word: unicase::UniCase<&str>,
+        vars: &'static [(
+            typos_vars::CategoriesByTag,
+            &'static typos_vars::VariantsMap,
+        )],
+    ) -> Corrections {
         let var = vars[0];
-        let var_categories = unsafe {
-            // Code-genned from a checked category-set, so known to be safe
-            typos_vars::CategorySet::from_bits_unchecked(var.0)
-        };
+        let var_categories = self.valid_categories(&var.0);
         if let Some(locale) = self.locale {
             if var_categories.contains(locale) {
                 // Already valid for the current locale.
-                Status::Valid
+                Vec::new()
             } else {
-                Status::Corrections(
-                    typos_vars::corrections(locale, *var.1)
-                        .iter()
-                        .copied()
-                        .map(Cow::Borrowed)
-                        .collect(),
-                )
+                // When being stricter than the corrections, the word itself can be among them
+                typos_vars::corrections(locale, *var.1)
+                    .iter()
+                    .filter(|(c, _)| unicase::UniCase::new(*c) != word)
+                    .map(|(c, tag)| (*c, varcon_details(*tag)))
+                    .collect()
             }
         } else {
             // All locales are valid
             if var_categories.is_empty() {
                 // But the word is never valid.
-                let mut unique: Vec<_> = var
-                    .1
-                    .iter()
-                    .flat_map(|v| v.iter())
-                    .copied()
-                    .map(Cow::Borrowed)
-                    .collect();
+                let mut unique: Vec<_> = var.1.iter().flat_map(|v| v.iter()).copied().collect();
+                // Keep the most accepted tag for each word
                 unique.sort_unstable();
-                unique.dedup();
-                Status::Corrections(unique)
+                unique.dedup_by_key(|(c, _)| *c);
+                unique
+                    .into_iter()
+                    .map(|(c, tag)| (c, varcon_details(tag)))
+                    .collect()
             } else {
-                Status::Valid
+                Vec::new()
             }
         }
     }
 }
 
+#[cfg(feature = "vars")]
+fn varcon_details(tag: typos_vars::Tag) -> typos::CorrectionDetails<'static> {
+    let (name, weight) = match tag {
+        typos_vars::Tag::Eq => ("eq", 1.0),
+        typos_vars::Tag::Variant => ("variant", 0.75),
+        typos_vars::Tag::Seldom => ("seldom", 0.5),
+        typos_vars::Tag::Possible => ("possible", 0.25),
+        typos_vars::Tag::Improper => ("improper", 0.1),
+    };
+    typos::CorrectionDetails {
+        source: Some(Cow::Borrowed("varcon")),
+        tag: Some(Cow::Borrowed(name)),
+        // A dialect is a matter of consistency, not a mistake
+        confidence: 0.8 * weight,
+    }
+}
+
 #[cfg(not(feature = "vars"))]
 impl BuiltIn {
-    fn chain_with_vars(&self, corrections: &'static [&'static str]) -> Status<'static> {
-        Status::Corrections(corrections.iter().map(|c| Cow::Borrowed(*c)).collect())
+    fn chain_with_vars(
+        &self,
+        dictionary: BundledDictionary,
+        corrections: &'static [&'static str],
+    ) -> Corrections {
+        let details = dictionary_details(dictionary);
+        corrections.iter().map(|c| (*c, details.clone())).collect()
     }
 
-    fn correct_with_vars(&self, _word: unicase::UniCase<&str>) -> Option<Status<'static>> {
+    fn correct_with_vars(&self, _word: unicase::UniCase<&str>) -> Option<Corrections> {
         None
     }
+
+    fn is_valid_with_vars(&self, _word: unicase::UniCase<&str>) -> bool {
+        false
+    }
 }
 
 impl typos::Dictionary for BuiltIn {
@@ -169,37 +337,66 @@ impl typos::Dictionary for BuiltIn {
     fn correct_word<'s, 'w>(&'s self, word: typos::tokens::Word<'w>) -> Option<Status<'s>> {
         BuiltIn::correct_word(self, word)
     }
+
+    fn correct_word_with_details<'s, 'w>(
+        &'s self,
+        word: typos::tokens::Word<'w>,
+    ) -> Option<(Status<'s>, Vec<typos::CorrectionDetails<'s>>)> {
+        BuiltIn::correct_word_with_details(self, word)
+    }
+
+    fn is_valid_word(&self, word: typos::tokens::Word<'_>) -> bool {
+        BuiltIn::is_valid_word(self, word)
+    }
 }
 
-fn case_correct(correction: &mut Cow<'_, str>, case: Case) {
+pub(crate) fn case_correct(correction: &mut Cow<'_, str>, case: Case) {
     match case {
         Case::Lower | Case::None => (),
-        Case::Title => match correction {
-            Cow::Borrowed(s) => {
-                let mut s = String::from(*s);
-                s[0..1].make_ascii_uppercase();
-                *correction = s.into();
-            }
-            Cow::Owned(s) => {
-                s[0..1].make_ascii_uppercase();
+        Case::Title => {
+            // Keep corrections with their own capitalization, like `iPhone` or `McDonald`
+            if correction.chars().any(char::is_uppercase) {
+                return;
             }
-        },
-        Case::Upper => match correction {
-            Cow::Borrowed(s) => {
-                let mut s = String::from(*s);
-                s.make_ascii_uppercase();
+            let mut chars = correction.chars();
+            if let Some(first) = chars.next() {
+                let mut s = String::with_capacity(correction.len());
+                push_titlecase(&mut s, first);
+                s.push_str(chars.as_str());
                 *correction = s.into();
             }
-            Cow::Owned(s) => {
-                s.make_ascii_uppercase();
+        }
+        Case::Upper => {
+            if correction.chars().any(char::is_lowercase) {
+                *correction = correction.to_uppercase().into();
             }
-        },
+        }
+    }
+}
+
+/// Unicode titlecase mapping, which only differs from uppercase for digraphs and characters
+/// that uppercase to multiple characters, like `ß` -> `Ss`.
+fn push_titlecase(s: &mut String, c: char) {
+    match c {
+        '\u{01C4}'..='\u{01C6}' => s.push('\u{01C5}'),
+        '\u{01C7}'..='\u{01C9}' => s.push('\u{01C8}'),
+        '\u{01CA}'..='\u{01CC}' => s.push('\u{01CB}'),
+        '\u{01F1}'..='\u{01F3}' => s.push('\u{01F2}'),
+        _ => {
+            let mut upper = c.to_uppercase();
+            s.extend(upper.next());
+            s.extend(upper.flat_map(char::to_lowercase));
+        }
     }
 }
 
 pub struct Override<'i, 'w, D> {
     identifiers: HashMap<&'i str, Status<'i>, ahash::RandomState>,
+    ignored_identifiers: regex::RegexSet,
     words: HashMap<unicase::UniCase<&'w str>, Status<'w>, ahash::RandomState>,
+    ignored_words: regex::RegexSet,
+    words_case_sensitive: HashMap<&'w str, Status<'w>, ahash::RandomState>,
+    file_words: HashMap<unicase::UniCase<&'w str>, (Status<'w>, &'w str), ahash::RandomState>,
     inner: D,
 }
 
@@ -207,7 +404,11 @@ impl<'i, 'w, D: typos::Dictionary> Override<'i, 'w, D> {
     pub fn new(inner: D) -> Self {
         Self {
             identifiers: Default::default(),
+            ignored_identifiers: regex::RegexSet::empty(),
             words: Default::default(),
+            ignored_words: regex::RegexSet::empty(),
+            words_case_sensitive: Default::default(),
+            file_words: Default::default(),
             inner,
         }
     }
@@ -216,12 +417,56 @@ impl<'i, 'w, D: typos::Dictionary> Override<'i, 'w, D> {
         self.identifiers = Self::interpret(identifiers).collect();
     }
 
+    /// Treat identifiers matching any of these patterns as valid.
+    pub fn ignored_identifiers<'r, I: IntoIterator<Item = &'r str>>(
+        &mut self,
+        patterns: I,
+    ) -> Result<(), regex::Error> {
+        self.ignored_identifiers = regex::RegexSet::new(patterns)?;
+        Ok(())
+    }
+
     pub fn words<I: Iterator<Item = (&'w str, &'w str)>>(&mut self, words: I) {
         self.words = Self::interpret(words)
             .map(|(k, v)| (UniCase::new(k), v))
             .collect();
     }
 
+    /// Treat words matching any of these patterns as valid.
+    pub fn ignored_words<'r, I: IntoIterator<Item = &'r str>>(
+        &mut self,
+        patterns: I,
+    ) -> Result<(), regex::Error> {
+        self.ignored_words = regex::RegexSet::new(patterns)?;
+        Ok(())
+    }
+
+    /// Corrections that only apply to an exact-case match, like `Github` -> `GitHub`.
+    ///
+    /// These take precedence over all other corrections and are used as-is, without adjusting
+    /// their case.
+    pub fn words_case_sensitive<I: Iterator<Item = (&'w str, &'w str)>>(&mut self, words: I) {
+        self.words_case_sensitive = Self::interpret(words).collect();
+    }
+
+    /// Corrections read from the dictionary file `source`, consulted after `words`.
+    ///
+    /// Later entries replace earlier ones for the same typo.
+    pub fn file_words<I: Iterator<Item = (&'w str, Vec<&'w str>)>>(
+        &mut self,
+        source: &'w str,
+        words: I,
+    ) {
+        self.file_words.extend(words.map(|(typo, corrections)| {
+            let status = match corrections.as_slice() {
+                [] => Status::Invalid,
+                [correction] if *correction == typo => Status::Valid,
+                _ => Status::Corrections(corrections.into_iter().map(Cow::Borrowed).collect()),
+            };
+            (UniCase::new(typo), (status, source))
+        }));
+    }
+
     fn interpret<'z, I: Iterator<Item = (&'z str, &'z str)>>(
         cases: I,
     ) -> impl Iterator<Item = (&'z str, Status<'z>)> {
@@ -240,34 +485,455 @@ impl<'i, 'w, D: typos::Dictionary> Override<'i, 'w, D> {
 
 impl<'i, 'w, D: typos::Dictionary> typos::Dictionary for Override<'i, 'w, D> {
     fn correct_ident<'s, 't>(&'s self, ident: typos::tokens::Identifier<'t>) -> Option<Status<'s>> {
+        self.correct_ident_with_details(ident)
+            .map(|(status, _)| status)
+    }
+
+    fn correct_word<'s, 't>(&'s self, word: typos::tokens::Word<'t>) -> Option<Status<'s>> {
+        self.correct_word_with_details(word)
+            .map(|(status, _)| status)
+    }
+
+    fn correct_ident_with_details<'s, 't>(
+        &'s self,
+        ident: typos::tokens::Identifier<'t>,
+    ) -> Option<(Status<'s>, Vec<typos::CorrectionDetails<'s>>)> {
+        if let Some(found) = self.correct_case_sensitive(ident.token()) {
+            return Some(found);
+        }
         // Skip hashing if we can
         if !self.identifiers.is_empty() {
-            self.identifiers
-                .get(ident.token())
-                .map(|c| c.borrow())
-                .or_else(|| self.inner.correct_ident(ident))
-        } else {
-            None
+            if let Some(status) = self.identifiers.get(ident.token()) {
+                return Some(with_user_details(status.borrow(), "extend-identifiers"));
+            }
         }
+        if !self.ignored_identifiers.is_empty() && self.ignored_identifiers.is_match(ident.token())
+        {
+            return Some((Status::Valid, Vec::new()));
+        }
+        self.inner.correct_ident_with_details(ident)
     }
 
-    fn correct_word<'s, 't>(&'s self, word: typos::tokens::Word<'t>) -> Option<Status<'s>> {
+    fn correct_word_with_details<'s, 't>(
+        &'s self,
+        word: typos::tokens::Word<'t>,
+    ) -> Option<(Status<'s>, Vec<typos::CorrectionDetails<'s>>)> {
+        if let Some(found) = self.correct_case_sensitive(word.token()) {
+            return Some(found);
+        }
         if word.case() == typos::tokens::Case::None {
             return None;
         }
 
         // Skip hashing if we can
-        let custom = if !self.words.is_empty() {
+        if !self.words.is_empty() {
             let w = UniCase::new(word.token());
             // HACK: couldn't figure out the lifetime issue with replacing `cloned` with `borrow`
-            self.words.get(&w).cloned()
+            if let Some(status) = self.words.get(&w).cloned() {
+                return Some(with_user_details(status, "extend-words"));
+            }
+        }
+        if !self.ignored_words.is_empty() && self.ignored_words.is_match(word.token()) {
+            return Some((Status::Valid, Vec::new()));
+        }
+        if !self.file_words.is_empty() {
+            if let Some((status, source)) = self.file_words.get(&UniCase::new(word.token())) {
+                let mut status = status.clone();
+                status
+                    .corrections_mut()
+                    .for_each(|s| case_correct(s, word.case()));
+                return Some(with_user_details(status, source));
+            }
+        }
+        self.inner.correct_word_with_details(word)
+    }
+
+    fn is_valid_word(&self, word: typos::tokens::Word<'_>) -> bool {
+        self.inner.is_valid_word(word)
+    }
+}
+
+/// Corrections from the user are trusted, unless ambiguous.
+fn with_user_details<'s>(
+    status: Status<'s>,
+    source: &'s str,
+) -> (Status<'s>, Vec<typos::CorrectionDetails<'s>>) {
+    let count = match &status {
+        Status::Corrections(corrections) => corrections.len(),
+        _ => 0,
+    };
+    let details = typos::CorrectionDetails {
+        source: Some(Cow::Borrowed(source)),
+        tag: None,
+        confidence: 1.0 / count as f32,
+    };
+    (status, vec![details; count])
+}
+
+impl<'i, 'w, D> Override<'i, 'w, D> {
+    fn correct_case_sensitive<'s>(
+        &'s self,
+        token: &str,
+    ) -> Option<(Status<'s>, Vec<typos::CorrectionDetails<'s>>)> {
+        // Skip hashing if we can
+        if self.words_case_sensitive.is_empty() {
+            return None;
+        }
+        self.words_case_sensitive
+            .get(token)
+            .map(|status| with_user_details(status.borrow(), "extend-words-case-sensitive"))
+    }
+}
+
+/// Report spellings from dialects other than the one a project mostly uses, like `colour` among
+/// `color`s, with the majority dialect's correction.
+///
+/// Words the inner dictionary reports are left to it.
+pub struct Consistent<D> {
+    inner: D,
+    majority: Option<BuiltIn>,
+}
+
+impl<D: typos::Dictionary> Consistent<D> {
+    /// `majority` is the dialect to be consistent with, if any.
+    pub fn new(
+        inner: D,
+        majority: Option<crate::config::Locale>,
+        locale_strictness: LocaleStrictness,
+    ) -> Self {
+        let majority = majority.map(|locale| {
+            let mut majority = BuiltIn::new(locale);
+            // Only spellings, typos are left to `inner`
+            majority
+                .dictionaries(&[])
+                .locale_strictness(locale_strictness);
+            majority
+        });
+        Self { inner, majority }
+    }
+}
+
+impl<D: typos::Dictionary> typos::Dictionary for Consistent<D> {
+    fn correct_ident<'s, 't>(&'s self, ident: typos::tokens::Identifier<'t>) -> Option<Status<'s>> {
+        self.inner.correct_ident(ident)
+    }
+
+    fn correct_ident_with_details<'s, 't>(
+        &'s self,
+        ident: typos::tokens::Identifier<'t>,
+    ) -> Option<(Status<'s>, Vec<typos::CorrectionDetails<'s>>)> {
+        self.inner.correct_ident_with_details(ident)
+    }
+
+    fn correct_word<'s, 't>(&'s self, word: typos::tokens::Word<'t>) -> Option<Status<'s>> {
+        self.correct_word_with_details(word)
+            .map(|(status, _)| status)
+    }
+
+    fn correct_word_with_details<'s, 't>(
+        &'s self,
+        word: typos::tokens::Word<'t>,
+    ) -> Option<(Status<'s>, Vec<typos::CorrectionDetails<'s>>)> {
+        let found = self.inner.correct_word_with_details(word);
+        match (&found, self.majority.as_ref()) {
+            (None, Some(majority)) | (Some((Status::Valid, _)), Some(majority)) => majority
+                .correct_word_with_details(word)
+                .filter(|(status, _)| status.is_correction())
+                .or(found),
+            _ => found,
+        }
+    }
+
+    fn is_valid_word(&self, word: typos::tokens::Word<'_>) -> bool {
+        self.inner.is_valid_word(word)
+    }
+}
+
+/// Report any word missing from the valid-word list, suggesting the closest valid words.
+///
+/// Words the inner dictionary has an opinion on are left to it.
+pub struct Strict<D> {
+    inner: D,
+    // Unknown words tend to repeat, like project-specific terms, so only search for them once
+    suggestions: std::sync::RwLock<HashMap<String, Vec<typos::Suggestion<'static>>>>,
+}
+
+impl<D: typos::Dictionary> Strict<D> {
+    pub fn new(inner: D) -> Self {
+        Self {
+            inner,
+            suggestions: Default::default(),
+        }
+    }
+
+    fn suggest(&self, word: typos::tokens::Word<'_>) -> Option<Status<'static>> {
+        let token = word.token();
+        if matches!(word.case(), Case::None | Case::Upper)
+            || token.len() <= MAX_IGNORED_UNKNOWN_LEN
+            || !token.bytes().all(|b| b.is_ascii_alphabetic())
+            || self.inner.is_valid_word(word)
+        {
+            return None;
+        }
+
+        let unknown = token.to_ascii_lowercase();
+        let cached = self.suggestions.read().unwrap().get(&unknown).cloned();
+        let suggestions = match cached {
+            Some(suggestions) => suggestions,
+            None => {
+                // Search without holding the lock so other threads aren't blocked on it
+                let suggestions = closest_words(unknown.as_bytes(), valid_words());
+                self.suggestions
+                    .write()
+                    .unwrap()
+                    .insert(unknown, suggestions.clone());
+                suggestions
+            }
+        };
+
+        let mut status = Status::Suggestions(suggestions);
+        status
+            .corrections_mut()
+            .for_each(|s| case_correct(s, word.case()));
+        Some(status)
+    }
+}
+
+impl<D: typos::Dictionary> typos::Dictionary for Strict<D> {
+    fn correct_ident<'s, 't>(&'s self, ident: typos::tokens::Identifier<'t>) -> Option<Status<'s>> {
+        self.correct_ident_with_details(ident)
+            .map(|(status, _)| status)
+    }
+
+    fn correct_word<'s, 't>(&'s self, word: typos::tokens::Word<'t>) -> Option<Status<'s>> {
+        self.correct_word_with_details(word)
+            .map(|(status, _)| status)
+    }
+
+    fn correct_ident_with_details<'s, 't>(
+        &'s self,
+        ident: typos::tokens::Identifier<'t>,
+    ) -> Option<(Status<'s>, Vec<typos::CorrectionDetails<'s>>)> {
+        self.inner.correct_ident_with_details(ident)
+    }
+
+    fn correct_word_with_details<'s, 't>(
+        &'s self,
+        word: typos::tokens::Word<'t>,
+    ) -> Option<(Status<'s>, Vec<typos::CorrectionDetails<'s>>)> {
+        self.inner
+            .correct_word_with_details(word)
+            .or_else(|| self.suggest(word).map(|status| (status, Vec::new())))
+    }
+
+    fn is_valid_word(&self, word: typos::tokens::Word<'_>) -> bool {
+        self.inner.is_valid_word(word)
+    }
+}
+
+// Too many short words, like abbreviations, are missing from the valid-word list
+const MAX_IGNORED_UNKNOWN_LEN: usize = 2;
+const MAX_SUGGESTIONS: usize = 3;
+/// Cost of inserting, removing, or replacing a character.
+const FULL_EDIT: u32 = 2;
+/// Cost of a slip of the finger, replacing a character with a neighboring key or swapping two
+/// characters.
+const NEAR_EDIT: u32 = 1;
+
+#[cfg(feature = "words")]
+fn valid_words() -> &'static [&'static str] {
+    typos_words::VALID_WORDS
+}
+
+#[cfg(not(feature = "words"))]
+fn valid_words() -> &'static [&'static str] {
+    &[]
+}
+
+/// Find the valid words closest to `typo`, ranked by [`next_row`]'s edit distance.
+///
+/// `words` must be sorted so it can be walked like a trie, skipping every word that starts with a
+/// prefix already too far from `typo`.
+fn closest_words(typo: &[u8], words: &[&'static str]) -> Vec<typos::Suggestion<'static>> {
+    let max_distance = if typo.len() <= 4 {
+        FULL_EDIT
+    } else {
+        2 * FULL_EDIT
+    };
+    let mut suggestions = Vec::new();
+    walk_words(
+        typo,
+        words,
+        0,
+        &[],
+        &first_row(typo),
+        max_distance,
+        &mut suggestions,
+    );
+    suggestions.sort_unstable_by(|a, b| (a.distance, &a.word).cmp(&(b.distance, &b.word)));
+    suggestions.truncate(MAX_SUGGESTIONS);
+    suggestions
+}
+
+/// Visit `words`, which all start with the same `depth`-long prefix.
+///
+/// `row` holds the distances from each start of `typo` to the prefix and `prev_row` to the prefix
+/// without its last character.
+fn walk_words(
+    typo: &[u8],
+    mut words: &[&'static str],
+    depth: usize,
+    prev_row: &[u32],
+    row: &[u32],
+    max_distance: u32,
+    suggestions: &mut Vec<typos::Suggestion<'static>>,
+) {
+    // Sorting puts the prefix itself first
+    if let Some((word, rest)) = words.split_first() {
+        if word.len() == depth {
+            let distance = row[typo.len()];
+            if distance <= max_distance {
+                suggestions.push(typos::Suggestion {
+                    word: Cow::Borrowed(*word),
+                    distance,
+                });
+            }
+            words = rest;
+        }
+    }
+
+    // A transposition can reach back to `prev_row`, so both need to be too far to skip the rest
+    let min_distance = |row: &[u32]| row.iter().copied().min().unwrap_or(0);
+    if max_distance < min_distance(row) && max_distance < min_distance(prev_row) + NEAR_EDIT {
+        return;
+    }
+
+    while let Some(first) = words.first() {
+        let c = first.as_bytes()[depth];
+        let end = words.partition_point(|word| word.as_bytes()[depth] <= c);
+        let (group, rest) = words.split_at(end);
+        let next_row = next_row(typo, prev_row, row, &first.as_bytes()[..=depth]);
+        walk_words(
+            typo,
+            group,
+            depth + 1,
+            row,
+            &next_row,
+            max_distance,
+            suggestions,
+        );
+        words = rest;
+    }
+}
+
+/// Distances from each start of `typo` to an empty word.
+fn first_row(typo: &[u8]) -> Vec<u32> {
+    (0..=typo.len() as u32).map(|i| i * FULL_EDIT).collect()
+}
+
+/// Damerau-Levenshtein (optimal string alignment) distances, weighted for QWERTY typing mistakes,
+/// from each start of `typo` to `word`, given the rows for `word` without its last one and two
+/// characters.
+fn next_row(typo: &[u8], prev_prev_row: &[u32], prev_row: &[u32], word: &[u8]) -> Vec<u32> {
+    let j = word.len();
+    let c = word[j - 1];
+    let mut row = Vec::with_capacity(prev_row.len());
+    row.push(j as u32 * FULL_EDIT);
+    for i in 1..=typo.len() {
+        let replace = if typo[i - 1] == c {
+            0
+        } else if is_adjacent_key(typo[i - 1], c) {
+            NEAR_EDIT
         } else {
-            None
+            FULL_EDIT
         };
-        custom.or_else(|| self.inner.correct_word(word))
+        let mut distance = (prev_row[i - 1] + replace)
+            .min(prev_row[i] + FULL_EDIT)
+            .min(row[i - 1] + FULL_EDIT);
+        if 1 < i && 1 < j && typo[i - 1] == word[j - 2] && typo[i - 2] == c {
+            distance = distance.min(prev_prev_row[i - 2] + NEAR_EDIT);
+        }
+        row.push(distance);
+    }
+    row
+}
+
+fn is_adjacent_key(a: u8, b: u8) -> bool {
+    const ROWS: [&[u8]; 3] = [b"qwertyuiop", b"asdfghjkl", b"zxcvbnm"];
+    let position = |c: u8| {
+        ROWS.iter().enumerate().find_map(|(row, keys)| {
+            keys.iter()
+                .position(|k| *k == c.to_ascii_lowercase())
+                .map(|column| (row, column))
+        })
+    };
+    match (position(a), position(b)) {
+        (Some((a_row, a_col)), Some((b_row, b_col))) => {
+            if a_row == b_row {
+                a_col + 1 == b_col || b_col + 1 == a_col
+            } else {
+                // Each row is offset by half a key to the right of the one above it
+                let ((upper_row, upper_col), (lower_row, lower_col)) = if a_row < b_row {
+                    ((a_row, a_col), (b_row, b_col))
+                } else {
+                    ((b_row, b_col), (a_row, a_col))
+                };
+                upper_row + 1 == lower_row && (upper_col == lower_col || upper_col == lower_col + 1)
+            }
+        }
+        _ => false,
     }
 }
 
+/// A typo and its corrections from a dictionary file.
+pub type DictFileEntry = (String, Vec<String>);
+
+/// Read a dictionary file of corrections.
+///
+/// Files ending in `.csv` are read as `typo,fix,fix2` and everything else as codespell's
+/// `typo->fix, fix2`.  Blank lines and lines starting with `#` are skipped.
+pub fn read_dict_file(path: &std::path::Path) -> Result<Vec<DictFileEntry>, anyhow::Error> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|err| anyhow::format_err!("Could not read `{}`: {}", path.display(), err))?;
+    let separator = if path.extension() == Some(std::ffi::OsStr::new("csv")) {
+        ","
+    } else {
+        "->"
+    };
+    parse_dict_file(&content, separator)
+        .map_err(|(line_num, err)| anyhow::format_err!("{}:{}: {}", path.display(), line_num, err))
+}
+
+fn parse_dict_file(content: &str, separator: &str) -> Result<Vec<DictFileEntry>, (usize, String)> {
+    let mut entries = Vec::new();
+    for (line_idx, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line_num = line_idx + 1;
+        let mut parts = line.splitn(2, separator);
+        let typo = parts.next().unwrap_or_default().trim();
+        let corrections = parts.next().ok_or_else(|| {
+            (
+                line_num,
+                format!("expected `typo{}correction`, got `{}`", separator, line),
+            )
+        })?;
+        if typo.is_empty() || typo.contains(char::is_whitespace) {
+            return Err((line_num, format!("invalid typo `{}`", typo)));
+        }
+        let corrections = corrections
+            .split(',')
+            .map(|c| c.trim())
+            .filter(|c| !c.is_empty())
+            .map(|c| c.to_owned())
+            .collect();
+        entries.push((typo.to_owned(), corrections));
+    }
+    Ok(entries)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -287,6 +953,60 @@ mod test {
         );
     }
 
+    #[cfg(all(feature = "dict", feature = "words"))]
+    #[test]
+    fn test_dict_segment() {
+        let dict = BuiltIn::new(crate::config::Locale::default());
+        let tokenizer = typos::tokens::TokenizerBuilder::new()
+            .segment_words(true)
+            .build();
+        let typos: Vec<_> = typos::check_str("recievedata getlenght copydata", &tokenizer, &dict)
+            .map(|typo| (typo.byte_offset, typo.typo.into_owned()))
+            .collect();
+        assert_eq!(
+            typos,
+            vec![(0, "recieve".to_owned()), (15, "lenght".to_owned())]
+        );
+    }
+
+    #[cfg(all(feature = "dict", feature = "codespell"))]
+    #[test]
+    fn test_dict_precedence() {
+        let word = typos::tokens::Word::new_unchecked("algorithems", typos::tokens::Case::Lower, 0);
+
+        let mut dict = BuiltIn::new(crate::config::Locale::default());
+        dict.dictionaries(&[BundledDictionary::Typos, BundledDictionary::Codespell]);
+        assert_eq!(
+            dict.correct_word(word),
+            Some(Status::Corrections(vec!["algorithm".into()]))
+        );
+        let (_, details) = dict.correct_word_with_details(word).unwrap();
+        assert_eq!(details[0].source, Some("typos".into()));
+
+        dict.dictionaries(&[BundledDictionary::Codespell, BundledDictionary::Typos]);
+        assert_eq!(
+            dict.correct_word(word),
+            Some(Status::Corrections(vec!["algorithms".into()]))
+        );
+        let (_, details) = dict.correct_word_with_details(word).unwrap();
+        assert_eq!(details[0].source, Some("codespell".into()));
+    }
+
+    #[cfg(feature = "codespell")]
+    #[test]
+    fn test_dict_opt_in() {
+        let word = typos::tokens::Word::new_unchecked("abailable", typos::tokens::Case::Lower, 0);
+
+        let mut dict = BuiltIn::new(crate::config::Locale::default());
+        assert_eq!(dict.correct_word(word), None);
+
+        dict.dictionaries(&[BundledDictionary::Codespell]);
+        assert_eq!(
+            dict.correct_word(word),
+            Some(Status::Corrections(vec!["available".into()]))
+        );
+    }
+
     #[cfg(feature = "vars")]
     #[test]
     fn test_varcon_no_locale() {
@@ -326,6 +1046,102 @@ mod test {
         );
     }
 
+    #[cfg(feature = "vars")]
+    #[test]
+    fn test_varcon_oxford_locale() {
+        let dict = BuiltIn::new(crate::config::Locale::EnGbOxendict);
+        let correction = dict.correct_word(typos::tokens::Word::new_unchecked(
+            "finalises",
+            typos::tokens::Case::Lower,
+            0,
+        ));
+        assert_eq!(
+            correction,
+            Some(Status::Corrections(vec!["finalizes".into()]))
+        );
+        let correction = dict.correct_word(typos::tokens::Word::new_unchecked(
+            "color",
+            typos::tokens::Case::Lower,
+            0,
+        ));
+        assert_eq!(correction, Some(Status::Corrections(vec!["colour".into()])));
+    }
+
+    #[cfg(feature = "vars")]
+    #[test]
+    fn test_varcon_oxford_same_locale() {
+        let dict = BuiltIn::new(crate::config::Locale::EnGbOxendict);
+        for word in ["finalizes", "colour"] {
+            let correction = dict.correct_word(typos::tokens::Word::new_unchecked(
+                word,
+                typos::tokens::Case::Lower,
+                0,
+            ));
+            assert_eq!(correction, Some(Status::Valid), "{}", word);
+        }
+    }
+
+    #[cfg(feature = "vars")]
+    #[test]
+    fn test_varcon_strictness() {
+        let correct = |locale, strictness, word| {
+            let mut dict = BuiltIn::new(locale);
+            dict.locale_strictness(strictness);
+            dict.correct_word(typos::tokens::Word::new_unchecked(
+                word,
+                typos::tokens::Case::Lower,
+                0,
+            ))
+            .map(|status| status.into_owned())
+        };
+        let valid = Some(Status::Valid);
+
+        // Improper
+        let locale = crate::config::Locale::EnGb;
+        let technicolor = Some(Status::Corrections(vec!["technicolor".into()]));
+        for strictness in [
+            LocaleStrictness::Lenient,
+            LocaleStrictness::Standard,
+            LocaleStrictness::Strict,
+        ] {
+            assert_eq!(correct(locale, strictness, "technicolour"), technicolor);
+        }
+        assert_eq!(
+            correct(locale, LocaleStrictness::Permissive, "technicolour"),
+            valid
+        );
+
+        // Possible
+        let locale = crate::config::Locale::EnUs;
+        let advertise = Some(Status::Corrections(vec!["advertise".into()]));
+        assert_eq!(
+            correct(locale, LocaleStrictness::Permissive, "advertize"),
+            valid
+        );
+        assert_eq!(
+            correct(locale, LocaleStrictness::default(), "advertize"),
+            valid
+        );
+        assert_eq!(
+            correct(locale, LocaleStrictness::Lenient, "advertize"),
+            valid
+        );
+        assert_eq!(
+            correct(locale, LocaleStrictness::Standard, "advertize"),
+            advertise
+        );
+        assert_eq!(
+            correct(locale, LocaleStrictness::Strict, "advertize"),
+            advertise
+        );
+
+        // Seldom
+        let aegis = Some(Status::Corrections(vec!["aegis".into()]));
+        assert_eq!(correct(locale, LocaleStrictness::Lenient, "egis"), valid);
+        assert_eq!(correct(locale, LocaleStrictness::Standard, "egis"), valid);
+        assert_eq!(correct(locale, LocaleStrictness::Strict, "egis"), aegis);
+    }
+
     #[cfg(all(feature = "dict", feature = "vars"))]
     #[test]
     fn test_dict_to_varcon() {
@@ -341,6 +1157,377 @@ mod test {
         );
     }
 
+    #[cfg(all(feature = "dict", feature = "vars"))]
+    #[test]
+    fn test_dict_to_varcon_details() {
+        let dict = BuiltIn::new(crate::config::Locale::EnGb);
+        let (_, details) = dict
+            .correct_word_with_details(typos::tokens::Word::new_unchecked(
+                "finallizes",
+                typos::tokens::Case::Lower,
+                0,
+            ))
+            .unwrap();
+        assert_eq!(
+            details,
+            vec![typos::CorrectionDetails {
+                source: Some("typos".into()),
+                tag: Some("eq".into()),
+                confidence: 0.8,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_override_ignored_identifiers() {
+        let mut dict = Override::new(BuiltIn::new(crate::config::Locale::default()));
+        dict.ignored_identifiers(vec!["^[a-z]{2}_[A-Z]{2}$"])
+            .unwrap();
+        let correction = typos::Dictionary::correct_ident(
+            &dict,
+            typos::tokens::Identifier::new_unchecked("en_US", typos::tokens::Case::None, 0),
+        );
+        assert_eq!(correction, Some(Status::Valid));
+        let correction = typos::Dictionary::correct_ident(
+            &dict,
+            typos::tokens::Identifier::new_unchecked("en_USA", typos::tokens::Case::None, 0),
+        );
+        assert_eq!(correction, None);
+    }
+
+    #[cfg(feature = "dict")]
+    #[test]
+    fn test_override_ignored_words() {
+        let mut dict = Override::new(BuiltIn::new(crate::config::Locale::default()));
+        dict.ignored_words(vec!["^finall"]).unwrap();
+        let correction = typos::Dictionary::correct_word(
+            &dict,
+            typos::tokens::Word::new_unchecked("finallizes", typos::tokens::Case::Lower, 0),
+        );
+        assert_eq!(correction, Some(Status::Valid));
+    }
+
+    #[test]
+    fn test_override_words_case_sensitive() {
+        let mut dict = Override::new(BuiltIn::new(crate::config::Locale::default()));
+        dict.words_case_sensitive(vec![("Github", "GitHub")].into_iter());
+        let correction = typos::Dictionary::correct_word(
+            &dict,
+            typos::tokens::Word::new_unchecked("Github", typos::tokens::Case::Title, 0),
+        );
+        assert_eq!(
+            correction,
+            Some(Status::Corrections(vec![Cow::Borrowed("GitHub")]))
+        );
+        let correction = typos::Dictionary::correct_ident(
+            &dict,
+            typos::tokens::Identifier::new_unchecked("Github", typos::tokens::Case::None, 0),
+        );
+        assert_eq!(
+            correction,
+            Some(Status::Corrections(vec![Cow::Borrowed("GitHub")]))
+        );
+        let correction = typos::Dictionary::correct_word(
+            &dict,
+            typos::tokens::Word::new_unchecked("GITHUB", typos::tokens::Case::Upper, 0),
+        );
+        assert_eq!(correction, None);
+    }
+
+    #[test]
+    fn test_override_file_words() {
+        let mut dict = Override::new(BuiltIn::new(crate::config::Locale::default()));
+        dict.file_words(
+            "team.txt",
+            vec![
+                ("ot", vec!["to", "of"]),
+                ("foobar", vec!["foobar"]),
+                ("frob", vec![]),
+            ]
+            .into_iter(),
+        );
+        let correction = typos::Dictionary::correct_word(
+            &dict,
+            typos::tokens::Word::new_unchecked("Ot", typos::tokens::Case::Title, 0),
+        );
+        assert_eq!(
+            correction,
+            Some(Status::Corrections(vec![
+                Cow::Borrowed("To"),
+                Cow::Borrowed("Of")
+            ]))
+        );
+        let correction = typos::Dictionary::correct_word(
+            &dict,
+            typos::tokens::Word::new_unchecked("foobar", typos::tokens::Case::Lower, 0),
+        );
+        assert_eq!(correction, Some(Status::Valid));
+        let correction = typos::Dictionary::correct_word(
+            &dict,
+            typos::tokens::Word::new_unchecked("frob", typos::tokens::Case::Lower, 0),
+        );
+        assert_eq!(correction, Some(Status::Invalid));
+    }
+
+    #[test]
+    fn test_override_source() {
+        let mut dict = Override::new(BuiltIn::new(crate::config::Locale::default()));
+        dict.words(vec![("foo", "bar")].into_iter());
+        dict.file_words("team.txt", vec![("ot", vec!["to"])].into_iter());
+        let (_, details) = typos::Dictionary::correct_word_with_details(
+            &dict,
+            typos::tokens::Word::new_unchecked("foo", typos::tokens::Case::Lower, 0),
+        )
+        .unwrap();
+        assert_eq!(details[0].source, Some("extend-words".into()));
+        let (_, details) = typos::Dictionary::correct_word_with_details(
+            &dict,
+            typos::tokens::Word::new_unchecked("ot", typos::tokens::Case::Lower, 0),
+        )
+        .unwrap();
+        assert_eq!(details[0].source, Some("team.txt".into()));
+    }
+
+    #[test]
+    fn test_override_details() {
+        let mut dict = Override::new(BuiltIn::new(crate::config::Locale::default()));
+        dict.words(vec![("foo", "bar")].into_iter());
+        dict.file_words("team.txt", vec![("ot", vec!["of", "to"])].into_iter());
+        let (_, details) = typos::Dictionary::correct_word_with_details(
+            &dict,
+            typos::tokens::Word::new_unchecked("foo", typos::tokens::Case::Lower, 0),
+        )
+        .unwrap();
+        assert_eq!(
+            details,
+            vec![typos::CorrectionDetails {
+                source: Some("extend-words".into()),
+                tag: None,
+                confidence: 1.0,
+            }]
+        );
+        let (_, details) = typos::Dictionary::correct_word_with_details(
+            &dict,
+            typos::tokens::Word::new_unchecked("ot", typos::tokens::Case::Lower, 0),
+        )
+        .unwrap();
+        let expected = typos::CorrectionDetails {
+            source: Some("team.txt".into()),
+            tag: None,
+            confidence: 0.5,
+        };
+        assert_eq!(details, vec![expected.clone(), expected]);
+    }
+
+    #[test]
+    fn test_override_ignored_details() {
+        let mut dict = Override::new(BuiltIn::new(crate::config::Locale::default()));
+        dict.ignored_words(vec!["^teh$"]).unwrap();
+        let found = typos::Dictionary::correct_word_with_details(
+            &dict,
+            typos::tokens::Word::new_unchecked("teh", typos::tokens::Case::Lower, 0),
+        );
+        assert_eq!(found, Some((Status::Valid, Vec::new())));
+    }
+
+    #[cfg(all(feature = "dict", feature = "words"))]
+    #[test]
+    fn test_strict_suggestions() {
+        let dict = Strict::new(BuiltIn::new(crate::config::Locale::default()));
+        let correction = typos::Dictionary::correct_word(
+            &dict,
+            typos::tokens::Word::new_unchecked("Compleetly", typos::tokens::Case::Title, 0),
+        );
+        let suggestions = match correction {
+            Some(Status::Suggestions(suggestions)) => suggestions,
+            _ => panic!("{:?}", correction),
+        };
+        assert_eq!(
+            suggestions[0],
+            typos::Suggestion {
+                word: "Completely".into(),
+                distance: NEAR_EDIT,
+            }
+        );
+    }
+
+    #[cfg(all(feature = "dict", feature = "vars"))]
+    #[test]
+    fn test_consistent() {
+        let dict = Consistent::new(
+            BuiltIn::new(crate::config::Locale::En),
+            Some(crate::config::Locale::EnUs),
+            LocaleStrictness::default(),
+        );
+        let correct = |word| {
+            typos::Dictionary::correct_word(
+                &dict,
+                typos::tokens::Word::new_unchecked(word, typos::tokens::Case::Lower, 0),
+            )
+        };
+        assert_eq!(
+            correct("colour"),
+            Some(Status::Corrections(vec!["color".into()]))
+        );
+        assert_eq!(correct("color"), None);
+        assert_eq!(
+            correct("teh"),
+            Some(Status::Corrections(vec!["the".into()]))
+        );
+        assert_eq!(correct("word"), None);
+    }
+
+    #[cfg(feature = "vars")]
+    #[test]
+    fn test_consistent_without_majority() {
+        let dict = Consistent::new(
+            BuiltIn::new(crate::config::Locale::En),
+            None,
+            LocaleStrictness::default(),
+        );
+        let correction = typos::Dictionary::correct_word(
+            &dict,
+            typos::tokens::Word::new_unchecked("colour", typos::tokens::Case::Lower, 0),
+        );
+        assert_eq!(correction, None);
+    }
+
+    #[cfg(all(feature = "dict", feature = "words"))]
+    #[test]
+    fn test_strict_defers() {
+        let dict = Strict::new(BuiltIn::new(crate::config::Locale::default()));
+        for (word, case) in [
+            ("recieved", typos::tokens::Case::Lower),
+            ("completely", typos::tokens::Case::Lower),
+            ("QWRT", typos::tokens::Case::Upper),
+            ("zq", typos::tokens::Case::Lower),
+        ] {
+            let correction = typos::Dictionary::correct_word(
+                &dict,
+                typos::tokens::Word::new_unchecked(word, case, 0),
+            );
+            assert!(
+                !matches!(correction, Some(Status::Suggestions(_))),
+                "{}: {:?}",
+                word,
+                correction
+            );
+        }
+    }
+
+    fn edit_distance(typo: &[u8], word: &[u8]) -> u32 {
+        let mut prev_row = Vec::new();
+        let mut row = first_row(typo);
+        for j in 1..=word.len() {
+            let next_row = next_row(typo, &prev_row, &row, &word[..j]);
+            prev_row = std::mem::replace(&mut row, next_row);
+        }
+        row[typo.len()]
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance(b"word", b"word"), 0);
+        // Neighboring key
+        assert_eq!(edit_distance(b"wprd", b"word"), NEAR_EDIT);
+        // Distant key
+        assert_eq!(edit_distance(b"wzrd", b"word"), FULL_EDIT);
+        // Transposition
+        assert_eq!(edit_distance(b"wrod", b"word"), NEAR_EDIT);
+        // Insertion and deletion
+        assert_eq!(edit_distance(b"wordd", b"word"), FULL_EDIT);
+        assert_eq!(edit_distance(b"wod", b"word"), FULL_EDIT);
+        assert_eq!(edit_distance(b"", b"word"), 4 * FULL_EDIT);
+    }
+
+    #[test]
+    fn test_closest_words() {
+        let words = [
+            "cat",
+            "cats",
+            "catsup",
+            "coat",
+            "complete",
+            "completely",
+            "completes",
+            "dog",
+            "word",
+            "words",
+            "world",
+        ];
+        for typo in ["wrod", "compleetly", "cta", "dgo", "wordl", "xyzzy"] {
+            let mut expected: Vec<_> = words
+                .iter()
+                .map(|word| typos::Suggestion {
+                    word: Cow::Borrowed(*word),
+                    distance: edit_distance(typo.as_bytes(), word.as_bytes()),
+                })
+                .filter(|s| {
+                    let max_distance = if typo.len() <= 4 {
+                        FULL_EDIT
+                    } else {
+                        2 * FULL_EDIT
+                    };
+                    s.distance <= max_distance
+                })
+                .collect();
+            expected.sort_unstable_by(|a, b| (a.distance, &a.word).cmp(&(b.distance, &b.word)));
+            expected.truncate(MAX_SUGGESTIONS);
+            assert_eq!(closest_words(typo.as_bytes(), &words), expected, "{}", typo);
+        }
+    }
+
+    #[test]
+    fn test_adjacent_key() {
+        assert!(is_adjacent_key(b'q', b'w'));
+        assert!(is_adjacent_key(b'a', b'q'));
+        assert!(is_adjacent_key(b'a', b'w'));
+        assert!(is_adjacent_key(b'z', b's'));
+        assert!(!is_adjacent_key(b'a', b'e'));
+        assert!(!is_adjacent_key(b'q', b'p'));
+        assert!(!is_adjacent_key(b'a', b'a'));
+    }
+
+    #[test]
+    fn test_parse_dict_file_codespell() {
+        let actual = parse_dict_file(
+            "# team words\nteh->the\n\nachive->achieve, archive,\n",
+            "->",
+        );
+        let expected = vec![
+            ("teh".to_owned(), vec!["the".to_owned()]),
+            (
+                "achive".to_owned(),
+                vec!["achieve".to_owned(), "archive".to_owned()],
+            ),
+        ];
+        assert_eq!(actual, Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_dict_file_csv() {
+        let actual = parse_dict_file("teh,the\r\nachive,achieve,archive\r\n", ",");
+        let expected = vec![
+            ("teh".to_owned(), vec!["the".to_owned()]),
+            (
+                "achive".to_owned(),
+                vec!["achieve".to_owned(), "archive".to_owned()],
+            ),
+        ];
+        assert_eq!(actual, Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_dict_file_error() {
+        let actual = parse_dict_file("teh->the\nachive\n", "->");
+        assert_eq!(
+            actual,
+            Err((2, "expected `typo->correction`, got `achive`".to_owned()))
+        );
+        let actual = parse_dict_file("teh->the\n->the\n", "->");
+        assert_eq!(actual, Err((2, "invalid typo ``".to_owned())));
+    }
+
     #[test]
     fn test_case_correct() {
         let cases = [
@@ -360,4 +1547,33 @@ mod test {
             assert_eq!(*expected, actual);
         }
     }
+
+    #[test]
+    fn test_case_correct_unicode() {
+        let cases = [
+            // Greek, including final sigma
+            ("σοφός", Case::Title, "Σοφός"),
+            ("λόγος", Case::Upper, "ΛΌΓΟΣ"),
+            // German `ß` has no single-character uppercase
+            ("straße", Case::Title, "Straße"),
+            ("straße", Case::Upper, "STRASSE"),
+            ("ßa", Case::Title, "Ssa"),
+            // Turkish dotted I isn't locale-tailored
+            ("istanbul", Case::Title, "Istanbul"),
+            ("istanbul", Case::Upper, "ISTANBUL"),
+            ("İzmir", Case::Title, "İzmir"),
+            ("ıi", Case::Upper, "II"),
+            // Digraphs have a distinct titlecase
+            ("ǆungla", Case::Title, "ǅungla"),
+            // Corrections with their own capitalization are kept
+            ("iPhone", Case::Title, "iPhone"),
+            ("McDonald", Case::Title, "McDonald"),
+            ("iPhone", Case::Upper, "IPHONE"),
+        ];
+        for (correction, case, expected) in cases.iter() {
+            let mut actual = Cow::Borrowed(*correction);
+            case_correct(&mut actual, *case);
+            assert_eq!(*expected, actual, "{:?} as {:?}", correction, case);
+        }
+    }
 }
@@ -1,5 +1,6 @@
 use bstr::ByteSlice;
 use encoding::Encoding;
+use itertools::Itertools;
 use std::io::Read;
 use std::io::Write;
 
@@ -28,15 +29,16 @@ impl FileChecker for Typos {
     ) -> Result<(), std::io::Error> {
         if policy.check_filenames {
             if let Some(file_name) = path.file_name().and_then(|s| s.to_str()) {
-                for typo in typos::check_str(file_name, policy.tokenizer, policy.dict) {
-                    let msg = report::Typo {
-                        context: Some(report::PathContext { path }.into()),
-                        buffer: std::borrow::Cow::Borrowed(file_name.as_bytes()),
-                        byte_offset: typo.byte_offset,
-                        typo: typo.typo.as_ref(),
-                        corrections: typo.corrections,
-                    };
-                    reporter.report(msg.into())?;
+                for finding in check_file_name(file_name, policy) {
+                    let byte_offset = finding.byte_offset();
+                    report_finding(
+                        finding,
+                        policy,
+                        report::PathContext { path }.into(),
+                        file_name.as_bytes(),
+                        byte_offset,
+                        reporter,
+                    )?;
                 }
             }
         }
@@ -48,17 +50,17 @@ impl FileChecker for Typos {
                 reporter.report(msg.into())?;
             } else {
                 let mut accum_line_num = AccumulateLineNum::new();
-                for typo in typos::check_bytes(&buffer, policy.tokenizer, policy.dict) {
-                    let line_num = accum_line_num.line_num(&buffer, typo.byte_offset);
-                    let (line, line_offset) = extract_line(&buffer, typo.byte_offset);
-                    let msg = report::Typo {
-                        context: Some(report::FileContext { path, line_num }.into()),
-                        buffer: std::borrow::Cow::Borrowed(line),
-                        byte_offset: line_offset,
-                        typo: typo.typo.as_ref(),
-                        corrections: typo.corrections,
-                    };
-                    reporter.report(msg.into())?;
+                for finding in check_bytes(&buffer, policy) {
+                    let line_num = accum_line_num.line_num(&buffer, finding.byte_offset());
+                    let (line, line_offset) = extract_line(&buffer, finding.byte_offset());
+                    report_finding(
+                        finding,
+                        policy,
+                        report::FileContext { path, line_num }.into(),
+                        line,
+                        line_offset,
+                        reporter,
+                    )?;
                 }
             }
         }
@@ -86,22 +88,23 @@ impl FileChecker for FixTypos {
             } else {
                 let mut fixes = Vec::new();
                 let mut accum_line_num = AccumulateLineNum::new();
-                for typo in typos::check_bytes(&buffer, policy.tokenizer, policy.dict) {
-                    if is_fixable(&typo) {
-                        fixes.push(typo.into_owned());
+                for finding in check_bytes(&buffer, policy) {
+                    if finding.is_fixable() {
+                        fixes.push(finding.into_typo().into_owned());
                     } else {
-                        let line_num = accum_line_num.line_num(&buffer, typo.byte_offset);
-                        let (line, line_offset) = extract_line(&buffer, typo.byte_offset);
-                        let msg = report::Typo {
-                            context: Some(report::FileContext { path, line_num }.into()),
-                            buffer: std::borrow::Cow::Borrowed(line),
-                            byte_offset: line_offset,
-                            typo: typo.typo.as_ref(),
-                            corrections: typo.corrections,
-                        };
-                        reporter.report(msg.into())?;
+                        let line_num = accum_line_num.line_num(&buffer, finding.byte_offset());
+                        let (line, line_offset) = extract_line(&buffer, finding.byte_offset());
+                        report_finding(
+                            finding,
+                            policy,
+                            report::FileContext { path, line_num }.into(),
+                            line,
+                            line_offset,
+                            reporter,
+                        )?;
                     }
                 }
+                fixes.sort_by_key(|typo| typo.byte_offset);
                 if !fixes.is_empty() || path == std::path::Path::new("-") {
                     let buffer = fix_buffer(buffer, fixes.into_iter());
                     write_file(path, content_type, buffer, reporter)?;
@@ -113,20 +116,22 @@ impl FileChecker for FixTypos {
         if policy.check_filenames {
             if let Some(file_name) = path.file_name().and_then(|s| s.to_str()) {
                 let mut fixes = Vec::new();
-                for typo in typos::check_str(file_name, policy.tokenizer, policy.dict) {
-                    if is_fixable(&typo) {
-                        fixes.push(typo.into_owned());
+                for finding in check_file_name(file_name, policy) {
+                    if finding.is_fixable() {
+                        fixes.push(finding.into_typo().into_owned());
                     } else {
-                        let msg = report::Typo {
-                            context: Some(report::PathContext { path }.into()),
-                            buffer: std::borrow::Cow::Borrowed(file_name.as_bytes()),
-                            byte_offset: typo.byte_offset,
-                            typo: typo.typo.as_ref(),
-                            corrections: typo.corrections,
-                        };
-                        reporter.report(msg.into())?;
+                        let byte_offset = finding.byte_offset();
+                        report_finding(
+                            finding,
+                            policy,
+                            report::PathContext { path }.into(),
+                            file_name.as_bytes(),
+                            byte_offset,
+                            reporter,
+                        )?;
                     }
                 }
+                fixes.sort_by_key(|typo| typo.byte_offset);
                 if !fixes.is_empty() {
                     let file_name = file_name.to_owned().into_bytes();
                     let new_name = fix_buffer(file_name, fixes.into_iter());
@@ -163,22 +168,23 @@ impl FileChecker for DiffTypos {
             } else {
                 let mut fixes = Vec::new();
                 let mut accum_line_num = AccumulateLineNum::new();
-                for typo in typos::check_bytes(&buffer, policy.tokenizer, policy.dict) {
-                    if is_fixable(&typo) {
-                        fixes.push(typo.into_owned());
+                for finding in check_bytes(&buffer, policy) {
+                    if finding.is_fixable() {
+                        fixes.push(finding.into_typo().into_owned());
                     } else {
-                        let line_num = accum_line_num.line_num(&buffer, typo.byte_offset);
-                        let (line, line_offset) = extract_line(&buffer, typo.byte_offset);
-                        let msg = report::Typo {
-                            context: Some(report::FileContext { path, line_num }.into()),
-                            buffer: std::borrow::Cow::Borrowed(line),
-                            byte_offset: line_offset,
-                            typo: typo.typo.as_ref(),
-                            corrections: typo.corrections,
-                        };
-                        reporter.report(msg.into())?;
+                        let line_num = accum_line_num.line_num(&buffer, finding.byte_offset());
+                        let (line, line_offset) = extract_line(&buffer, finding.byte_offset());
+                        report_finding(
+                            finding,
+                            policy,
+                            report::FileContext { path, line_num }.into(),
+                            line,
+                            line_offset,
+                            reporter,
+                        )?;
                     }
                 }
+                fixes.sort_by_key(|typo| typo.byte_offset);
                 if !fixes.is_empty() {
                     new_content = fix_buffer(buffer.clone(), fixes.into_iter());
                     content = buffer
@@ -191,20 +197,22 @@ impl FileChecker for DiffTypos {
         if policy.check_filenames {
             if let Some(file_name) = path.file_name().and_then(|s| s.to_str()) {
                 let mut fixes = Vec::new();
-                for typo in typos::check_str(file_name, policy.tokenizer, policy.dict) {
-                    if is_fixable(&typo) {
-                        fixes.push(typo.into_owned());
+                for finding in check_file_name(file_name, policy) {
+                    if finding.is_fixable() {
+                        fixes.push(finding.into_typo().into_owned());
                     } else {
-                        let msg = report::Typo {
-                            context: Some(report::PathContext { path }.into()),
-                            buffer: std::borrow::Cow::Borrowed(file_name.as_bytes()),
-                            byte_offset: typo.byte_offset,
-                            typo: typo.typo.as_ref(),
-                            corrections: typo.corrections,
-                        };
-                        reporter.report(msg.into())?;
+                        let byte_offset = finding.byte_offset();
+                        report_finding(
+                            finding,
+                            policy,
+                            report::PathContext { path }.into(),
+                            file_name.as_bytes(),
+                            byte_offset,
+                            reporter,
+                        )?;
                     }
                 }
+                fixes.sort_by_key(|typo| typo.byte_offset);
                 if !fixes.is_empty() {
                     let file_name = file_name.to_owned().into_bytes();
                     let new_name = fix_buffer(file_name, fixes.into_iter());
@@ -382,6 +390,133 @@ impl FileChecker for FoundFiles {
     }
 }
 
+/// Find the dialect a project mostly uses, to report spellings from the others, like `colour`
+/// in a project that mostly spells it `color`.
+///
+/// The majority dialect is decided by every checked file, so first walk them with
+/// [`VariantConsistency::tally`], then pass [`VariantConsistency::majority`] to
+/// [`ConfigEngine::set_majority_locale`][crate::policy::ConfigEngine::set_majority_locale].
+pub struct VariantConsistency {
+    dialects: Vec<Dialect>,
+}
+
+struct Dialect {
+    locale: crate::config::Locale,
+    /// Only accepts the dialect's preferred spellings
+    preferred: crate::dict::BuiltIn,
+    count: std::sync::atomic::AtomicUsize,
+}
+
+impl VariantConsistency {
+    pub fn new() -> Self {
+        let dialects = [
+            crate::config::Locale::EnUs,
+            crate::config::Locale::EnGb,
+            crate::config::Locale::EnGbOxendict,
+            crate::config::Locale::EnCa,
+            crate::config::Locale::EnAu,
+        ]
+        .iter()
+        .map(|locale| {
+            let mut preferred = crate::dict::BuiltIn::new(*locale);
+            preferred
+                .dictionaries(&[])
+                .locale_strictness(crate::config::LocaleStrictness::Strict);
+            Dialect {
+                locale: *locale,
+                preferred,
+                count: Default::default(),
+            }
+        })
+        .collect();
+        Self { dialects }
+    }
+
+    /// Count which dialects each file's spellings are preferred in.
+    pub fn tally(&self) -> TallyVariants<'_> {
+        TallyVariants { consistency: self }
+    }
+
+    fn tally_word(&self, word: typos::tokens::Word<'_>) {
+        for dialect in self.dialects.iter() {
+            match dialect.preferred.correct_word(word) {
+                Some(typos::Status::Valid) => {
+                    dialect
+                        .count
+                        .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                }
+                Some(_) => {}
+                // Spelled the same in every dialect
+                None => return,
+            }
+        }
+    }
+
+    /// The dialect with the most preferred spellings, with ties going to the first listed.
+    pub fn majority(&self) -> Option<crate::config::Locale> {
+        let mut majority = None;
+        let mut max = 0;
+        for dialect in self.dialects.iter() {
+            let count = dialect.count.load(std::sync::atomic::Ordering::Relaxed);
+            if max < count {
+                majority = Some(dialect.locale);
+                max = count;
+            }
+        }
+        majority
+    }
+}
+
+impl Default for VariantConsistency {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// First pass of [`VariantConsistency`].
+pub struct TallyVariants<'c> {
+    consistency: &'c VariantConsistency,
+}
+
+impl<'c> FileChecker for TallyVariants<'c> {
+    fn check_file(
+        &self,
+        path: &std::path::Path,
+        explicit: bool,
+        policy: &crate::policy::Policy,
+        reporter: &dyn report::Report,
+    ) -> Result<(), std::io::Error> {
+        if policy.check_files {
+            let (buffer, content_type) = read_file(path, reporter)?;
+            if explicit || policy.binary || !content_type.is_binary() {
+                let suppressions = Suppressions::new(&buffer, policy.comments);
+                for span in policy.extractor.extract(&buffer) {
+                    let start = span.start;
+                    for word in policy
+                        .tokenizer
+                        .parse_bytes(&buffer[span])
+                        .flat_map(|i| i.split())
+                    {
+                        if suppressions.contains(start + word.offset()) {
+                            continue;
+                        }
+                        // Typos, and spellings the config corrects, don't show which dialect is
+                        // used
+                        match policy.dict.correct_word(word) {
+                            None | Some(typos::Status::Valid) => {
+                                self.consistency.tally_word(word);
+                            }
+                            Some(_) => {}
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 fn read_file(
     path: &std::path::Path,
     reporter: &dyn report::Report,
@@ -526,6 +661,235 @@ fn extract_line(buffer: &[u8], byte_offset: usize) -> (&[u8], usize) {
     (line, line_offset)
 }
 
+/// A typo or forbidden word, in the order they appear.
+enum Finding<'b> {
+    Typo(typos::Typo<'b>),
+    Forbidden {
+        typo: typos::Typo<'b>,
+        rule: &'b crate::forbid::Rule<'b>,
+        /// Only whole identifiers are fixed, to not rewrite code like `whitelistEntries`
+        fixable: bool,
+    },
+}
+
+impl<'b> Finding<'b> {
+    fn byte_offset(&self) -> usize {
+        match self {
+            Finding::Typo(typo) | Finding::Forbidden { typo, .. } => typo.byte_offset,
+        }
+    }
+
+    fn is_fixable(&self) -> bool {
+        match self {
+            Finding::Typo(typo) => is_fixable(typo),
+            Finding::Forbidden { typo, fixable, .. } => *fixable && is_fixable(typo),
+        }
+    }
+
+    fn into_typo(self) -> typos::Typo<'b> {
+        match self {
+            Finding::Typo(typo) | Finding::Forbidden { typo, .. } => typo,
+        }
+    }
+}
+
+fn report_finding(
+    finding: Finding<'_>,
+    policy: &crate::policy::Policy,
+    context: report::Context<'_>,
+    buffer: &[u8],
+    byte_offset: usize,
+    reporter: &dyn report::Report,
+) -> Result<(), std::io::Error> {
+    match finding {
+        Finding::Typo(typo) => {
+            let msg = report::Typo {
+                context: Some(context),
+                buffer: std::borrow::Cow::Borrowed(buffer),
+                byte_offset,
+                typo: typo.typo.as_ref(),
+                corrections: typo.corrections,
+                details: typo.details,
+                severity: policy.severities.get(&typo.typo),
+            };
+            reporter.report(msg.into())
+        }
+        Finding::Forbidden { typo, rule, .. } => {
+            let replacement = match typo.corrections {
+                typos::Status::Corrections(corrections) => corrections,
+                _ => Vec::new(),
+            };
+            let msg = report::Forbidden {
+                context: Some(context),
+                buffer: std::borrow::Cow::Borrowed(buffer),
+                byte_offset,
+                word: typo.typo.as_ref(),
+                replacement,
+                message: rule.message,
+                severity: rule.severity,
+            };
+            reporter.report(msg.into())
+        }
+    }
+}
+
+fn check_file_name<'n>(
+    file_name: &'n str,
+    policy: &crate::policy::Policy<'n, 'n>,
+) -> impl Iterator<Item = Finding<'n>> {
+    let forbidden = policy.forbidden;
+    // File names are too terse for unknown words, like extensions, to be worth reporting
+    let spelling = typos::check_str(file_name, policy.tokenizer, policy.dict)
+        .filter(|typo| !typo.corrections.is_suggestion())
+        .map(Finding::Typo);
+    let forbidden = policy
+        .tokenizer
+        .parse_str(file_name)
+        .flat_map(move |ident| find_forbidden(ident, 0, forbidden));
+    spelling.merge_by(forbidden, |s, f| s.byte_offset() <= f.byte_offset())
+}
+
+fn check_bytes<'b>(
+    buffer: &'b [u8],
+    policy: &crate::policy::Policy<'b, 'b>,
+) -> impl Iterator<Item = Finding<'b>> {
+    let suppressions = Suppressions::new(buffer, policy.comments);
+    let tokenizer = policy.tokenizer;
+    let dict = policy.dict;
+    let forbidden = policy.forbidden;
+    let check_doubled_words = policy.check_doubled_words;
+    policy
+        .extractor
+        .extract(buffer)
+        .into_iter()
+        .flat_map(move |span| {
+            let start = span.start;
+            let span = &buffer[span];
+            let spelling = typos::check_bytes(span, tokenizer, dict);
+            let doubled = check_doubled_words
+                .then(|| typos::check_doubled_bytes(span, tokenizer))
+                .into_iter()
+                .flatten();
+            let spelling = spelling
+                .merge_by(doubled, |s, d| s.byte_offset <= d.byte_offset)
+                .map(move |mut typo| {
+                    typo.byte_offset += start;
+                    Finding::Typo(typo)
+                });
+            // Skip tokenizing again if we can
+            let forbidden = (!forbidden.is_empty())
+                .then(|| {
+                    tokenizer
+                        .parse_bytes(span)
+                        .flat_map(move |ident| find_forbidden(ident, start, forbidden))
+                })
+                .into_iter()
+                .flatten();
+            spelling.merge_by(forbidden, |s, f| s.byte_offset() <= f.byte_offset())
+        })
+        .filter(move |finding| !suppressions.contains(finding.byte_offset()))
+}
+
+fn find_forbidden<'b>(
+    ident: typos::tokens::Identifier<'b>,
+    start: usize,
+    forbidden: &'b crate::forbid::Forbidden<'b>,
+) -> impl Iterator<Item = Finding<'b>> {
+    ident.split().filter_map(move |word| {
+        let (rule, corrections) = forbidden.find(word)?;
+        let mut typo = typos::Typo::default();
+        typo.byte_offset = start + word.offset();
+        typo.typo = word.token().into();
+        typo.corrections = corrections;
+        Some(Finding::Forbidden {
+            typo,
+            rule,
+            fixable: word.token().len() == ident.token().len(),
+        })
+    })
+}
+
+/// Regions of a buffer that in-file directives exclude from spell checking.
+///
+/// Directives are only recognized in comments.  Supported directives:
+/// - `typos: ignore-line`: the line containing the directive
+/// - `typos: ignore-next-line`: the line after the directive
+/// - `typos: off` / `typos: on`: every line from `off` through the matching `on`, or the end of
+///   the buffer
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Suppressions {
+    ranges: Vec<std::ops::Range<usize>>,
+}
+
+impl Suppressions {
+    const MARKER: &'static [u8] = b"typos:";
+
+    fn new(buffer: &[u8], comments: crate::spans::Comments) -> Self {
+        let mut ranges = Vec::new();
+        let mut off_start = None;
+        let comments = comments.extract(buffer);
+        for marker_start in buffer.find_iter(Self::MARKER) {
+            if !comments.iter().any(|c| c.contains(&marker_start)) {
+                continue;
+            }
+            let is_word_start = buffer[0..marker_start]
+                .last()
+                .map(|b| !b.is_ascii_alphanumeric() && *b != b'_')
+                .unwrap_or(true);
+            if !is_word_start {
+                continue;
+            }
+
+            let (line_start, line_end) = line_bounds(buffer, marker_start);
+            let directive = buffer[marker_start + Self::MARKER.len()..line_end].trim_start();
+            let directive_len = directive
+                .iter()
+                .take_while(|b| b.is_ascii_alphabetic() || **b == b'-')
+                .count();
+            match &directive[0..directive_len] {
+                b"ignore-line" => {
+                    ranges.push(line_start..line_end);
+                }
+                b"ignore-next-line" if line_end < buffer.len() => {
+                    let (next_start, next_end) = line_bounds(buffer, line_end + 1);
+                    ranges.push(next_start..next_end);
+                }
+                b"off" => {
+                    off_start.get_or_insert(line_start);
+                }
+                b"on" => {
+                    if let Some(start) = off_start.take() {
+                        ranges.push(start..line_end);
+                    }
+                }
+                _ => {}
+            }
+        }
+        if let Some(start) = off_start {
+            ranges.push(start..buffer.len());
+        }
+        Self { ranges }
+    }
+
+    fn contains(&self, byte_offset: usize) -> bool {
+        self.ranges.iter().any(|r| r.contains(&byte_offset))
+    }
+}
+
+/// Start and end (excluding the newline) of the line containing `byte_offset`.
+fn line_bounds(buffer: &[u8], byte_offset: usize) -> (usize, usize) {
+    let line_start = buffer[0..byte_offset]
+        .rfind_byte(b'\n')
+        // Skip the newline
+        .map(|s| s + 1)
+        .unwrap_or(0);
+    let line_end = buffer[byte_offset..]
+        .find_byte(b'\n')
+        .map(|s| s + byte_offset)
+        .unwrap_or_else(|| buffer.len());
+    (line_start, line_end)
+}
+
 fn extract_fix<'t>(typo: &'t typos::Typo<'t>) -> Option<&'t str> {
     match &typo.corrections {
         typos::Status::Corrections(c) if c.len() == 1 => Some(c[0].as_ref()),
@@ -539,8 +903,14 @@ fn is_fixable(typo: &typos::Typo<'_>) -> bool {
 
 fn fix_buffer(mut buffer: Vec<u8>, typos: impl Iterator<Item = typos::Typo<'static>>) -> Vec<u8> {
     let mut offset = 0isize;
+    let mut fixed_end = 0;
     for typo in typos {
         let fix = extract_fix(&typo).expect("Caller only provides fixable typos");
+        if typo.byte_offset < fixed_end {
+            // Overlaps an earlier fix; leave it for the next run
+            continue;
+        }
+        fixed_end = typo.byte_offset + typo.typo.len();
         let start = ((typo.byte_offset as isize) + offset) as usize;
         let end = start + typo.typo.len();
 
@@ -554,11 +924,12 @@ fn fix_buffer(mut buffer: Vec<u8>, typos: impl Iterator<Item = typos::Typo<'stat
 pub fn walk_path(
     walk: ignore::Walk,
     checks: &dyn FileChecker,
-    engine: &crate::policy::ConfigEngine,
+    engine: &mut crate::policy::ConfigEngine,
     reporter: &dyn report::Report,
 ) -> Result<(), ignore::Error> {
+    let engine = std::sync::RwLock::new(engine);
     for entry in walk {
-        walk_entry(entry, checks, engine, reporter)?;
+        walk_entry(entry, checks, &engine, reporter)?;
     }
     Ok(())
 }
@@ -566,13 +937,14 @@ pub fn walk_path(
 pub fn walk_path_parallel(
     walk: ignore::WalkParallel,
     checks: &dyn FileChecker,
-    engine: &crate::policy::ConfigEngine,
+    engine: &mut crate::policy::ConfigEngine,
     reporter: &dyn report::Report,
 ) -> Result<(), ignore::Error> {
+    let engine = std::sync::RwLock::new(engine);
     let error: std::sync::Mutex<Result<(), ignore::Error>> = std::sync::Mutex::new(Ok(()));
     walk.run(|| {
         Box::new(|entry: Result<ignore::DirEntry, ignore::Error>| {
-            match walk_entry(entry, checks, engine, reporter) {
+            match walk_entry(entry, checks, &engine, reporter) {
                 Ok(()) => ignore::WalkState::Continue,
                 Err(err) => {
                     *error.lock().unwrap() = Err(err);
@@ -588,7 +960,7 @@ pub fn walk_path_parallel(
 fn walk_entry(
     entry: Result<ignore::DirEntry, ignore::Error>,
     checks: &dyn FileChecker,
-    engine: &crate::policy::ConfigEngine,
+    engine: &std::sync::RwLock<&mut crate::policy::ConfigEngine>,
     reporter: &dyn report::Report,
 ) -> Result<(), ignore::Error> {
     let entry = match entry {
@@ -598,15 +970,30 @@ fn walk_entry(
             return Ok(());
         }
     };
-    if entry.file_type().map(|t| t.is_file()).unwrap_or(true) {
+    if entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+        // The walk's own directory was initialized before walking.  A directory is visited
+        // before its entries, so its config is there for them.
+        if entry.depth() != 0 {
+            let dir = entry.path().canonicalize()?;
+            let config = engine.read().unwrap().nested_config(&dir);
+            if let Some(config) = config.map_err(|err| config_error(&dir, err))? {
+                engine
+                    .write()
+                    .unwrap()
+                    .init_nested_dir(&dir, config)
+                    .map_err(|err| config_error(&dir, err))?;
+            }
+        }
+    } else if entry.file_type().map(|t| t.is_file()).unwrap_or(true) {
         let explicit = entry.depth() == 0;
         let (path, lookup_path) = if entry.is_stdin() {
             let path = std::path::Path::new("-");
-            (path, std::env::current_dir()?)
+            (path, std::env::current_dir()?.canonicalize()?)
         } else {
             let path = entry.path();
-            (path, path.canonicalize()?)
+            (path, lookup_path(path)?)
         };
+        let engine = engine.read().unwrap();
         let policy = engine.policy(&lookup_path);
         checks.check_file(path, explicit, &policy, reporter)?;
     }
@@ -614,6 +1001,27 @@ fn walk_entry(
     Ok(())
 }
 
+/// Absolute path for looking up `path`'s policy, canonicalizing its directory like the ones
+/// initialized rather than following `path` itself if it is a symlink.
+fn lookup_path(path: &std::path::Path) -> Result<std::path::PathBuf, std::io::Error> {
+    let dir = path
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or_else(|| std::path::Path::new("."));
+    match path.file_name() {
+        Some(name) => Ok(dir.canonicalize()?.join(name)),
+        None => path.canonicalize(),
+    }
+}
+
+fn config_error(dir: &std::path::Path, err: anyhow::Error) -> ignore::Error {
+    let err = std::io::Error::new(std::io::ErrorKind::InvalidData, err);
+    ignore::Error::WithPath {
+        path: dir.to_owned(),
+        err: Box::new(ignore::Error::Io(err)),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -622,10 +1030,12 @@ mod test {
         let line = line.as_bytes().to_vec();
         let corrections: Vec<_> = corrections
             .into_iter()
-            .map(|(byte_offset, typo, correction)| typos::Typo {
-                byte_offset,
-                typo: typo.into(),
-                corrections: typos::Status::Corrections(vec![correction.into()]),
+            .map(|(byte_offset, typo, correction)| {
+                let mut fix = typos::Typo::default();
+                fix.byte_offset = byte_offset;
+                fix.typo = typo.into();
+                fix.corrections = typos::Status::Corrections(vec![correction.into()]);
+                fix
             })
             .collect();
         let actual = fix_buffer(line, corrections.into_iter());
@@ -677,6 +1087,83 @@ mod test {
         assert_eq!(actual, "foo happy world");
     }
 
+    #[test]
+    fn test_fix_buffer_overlap() {
+        let actual = fix_simple(
+            "teh teh foo",
+            vec![(0, "teh", "the"), (0, "teh teh", "teh"), (4, "teh", "the")],
+        );
+        assert_eq!(actual, "the the foo");
+    }
+
+    #[test]
+    fn test_suppressions_none() {
+        let buffer = b"hello\nworld";
+        let suppressions = Suppressions::new(buffer, Default::default());
+        assert_eq!(suppressions, Suppressions::default());
+    }
+
+    #[test]
+    fn test_suppressions_ignore_line() {
+        let buffer = b"1\nteh // typos: ignore-line\n3";
+        let suppressions = Suppressions::new(buffer, Default::default());
+        assert!(!suppressions.contains(0));
+        assert!(suppressions.contains(2));
+        assert!(!suppressions.contains(buffer.len() - 1));
+    }
+
+    #[test]
+    fn test_suppressions_ignore_next_line() {
+        let buffer = b"# typos: ignore-next-line\nteh\n3";
+        let suppressions = Suppressions::new(buffer, Default::default());
+        assert!(suppressions.contains(26));
+        assert!(!suppressions.contains(buffer.len() - 1));
+    }
+
+    #[test]
+    fn test_suppressions_ignore_next_line_at_end() {
+        let buffer = b"# typos: ignore-next-line";
+        let suppressions = Suppressions::new(buffer, Default::default());
+        assert_eq!(suppressions, Suppressions::default());
+    }
+
+    #[test]
+    fn test_suppressions_off_on() {
+        let buffer = b"1\n# typos: off\nteh\n# typos: on\nteh";
+        let suppressions = Suppressions::new(buffer, Default::default());
+        assert!(!suppressions.contains(0));
+        assert!(suppressions.contains(15));
+        assert!(!suppressions.contains(buffer.len() - 1));
+    }
+
+    #[test]
+    fn test_suppressions_off_to_end() {
+        let buffer = b"1\n# typos: off\nteh";
+        let suppressions = Suppressions::new(buffer, Default::default());
+        assert!(!suppressions.contains(0));
+        assert!(suppressions.contains(buffer.len() - 1));
+    }
+
+    #[test]
+    fn test_suppressions_only_in_comments() {
+        let buffer = b"let s = \"typos: ignore-line\";\n// typos: ignore-next-line\nteh";
+        let rust = crate::spans::Syntax::from_type("rust").unwrap();
+        let suppressions = Suppressions::new(buffer, crate::spans::Comments::Code(rust));
+        assert!(!suppressions.contains(0));
+        assert!(suppressions.contains(buffer.len() - 1));
+
+        let buffer = b"| `typos: off` | Skipped |\nteh";
+        let suppressions = Suppressions::new(buffer, crate::spans::Comments::Markup);
+        assert_eq!(suppressions, Suppressions::default());
+    }
+
+    #[test]
+    fn test_suppressions_unknown_directive() {
+        let buffer = b"# typos: offset\n# mytypos: off\nteh";
+        let suppressions = Suppressions::new(buffer, Default::default());
+        assert_eq!(suppressions, Suppressions::default());
+    }
+
     #[test]
     fn test_line_count_first() {
         let mut accum_line_num = AccumulateLineNum::new();
@@ -778,4 +1265,40 @@ mod test {
         assert_eq!(offset, 28);
         assert_eq!(line[offset], buffer[buffer_offset]);
     }
+
+    #[cfg(feature = "vars")]
+    fn tally_words(consistency: &VariantConsistency, text: &str) {
+        let tokenizer = typos::tokens::Tokenizer::new();
+        for word in tokenizer.parse_str(text).flat_map(|i| i.split()) {
+            consistency.tally_word(word);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "vars")]
+    fn test_variant_consistency_majority() {
+        let consistency = VariantConsistency::new();
+        assert_eq!(consistency.majority(), None);
+
+        tally_words(&consistency, "The colour of the color");
+        // Ties go to the first dialect
+        assert_eq!(consistency.majority(), Some(crate::config::Locale::EnUs));
+
+        tally_words(&consistency, "Other colours");
+        assert_eq!(consistency.majority(), Some(crate::config::Locale::EnGb));
+
+        tally_words(&consistency, "Organize");
+        assert_eq!(
+            consistency.majority(),
+            Some(crate::config::Locale::EnGbOxendict)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "vars")]
+    fn test_variant_consistency_ignores_invariant() {
+        let consistency = VariantConsistency::new();
+        tally_words(&consistency, "The words are spelled the same");
+        assert_eq!(consistency.majority(), None);
+    }
 }
@@ -0,0 +1,97 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use typos::Status;
+
+/// Words to report with an explanation, whatever their spelling, like terms a style guide
+/// rules out.
+#[derive(Clone, Debug, Default)]
+pub struct Forbidden<'w> {
+    words: HashMap<unicase::UniCase<&'w str>, Rule<'w>, ahash::RandomState>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rule<'w> {
+    /// What to use instead, fixed automatically when there is only one.
+    pub replacement: Vec<&'w str>,
+    /// Why the word is forbidden.
+    pub message: Option<&'w str>,
+    pub severity: crate::config::Severity,
+}
+
+impl<'w> Forbidden<'w> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn insert(&mut self, word: &'w str, rule: Rule<'w>) {
+        self.words.insert(unicase::UniCase::new(word), rule);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// The rule forbidding the word, with its replacements matching the word's case.
+    pub fn find<'s>(
+        &'s self,
+        word_token: typos::tokens::Word<'s>,
+    ) -> Option<(&'s Rule<'s>, Status<'s>)> {
+        let rule = self.words.get(&unicase::UniCase::new(word_token.token()))?;
+        let status = if rule.replacement.is_empty() {
+            Status::Invalid
+        } else {
+            Status::Corrections(
+                rule.replacement
+                    .iter()
+                    .map(|r| {
+                        let mut r = Cow::Borrowed(*r);
+                        crate::dict::case_correct(&mut r, word_token.case());
+                        r
+                    })
+                    .collect(),
+            )
+        };
+        Some((rule, status))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rule(replacement: Vec<&'static str>) -> Rule<'static> {
+        Rule {
+            replacement,
+            message: None,
+            severity: crate::config::Severity::Error,
+        }
+    }
+
+    #[test]
+    fn test_find_matches_case() {
+        let mut forbidden = Forbidden::new();
+        forbidden.insert("whitelist", rule(vec!["allowlist"]));
+
+        let word = typos::tokens::Word::new("Whitelist", 0).unwrap();
+        let (_, status) = forbidden.find(word).unwrap();
+        assert_eq!(status, Status::Corrections(vec!["Allowlist".into()]));
+
+        let word = typos::tokens::Word::new("WHITELIST", 0).unwrap();
+        let (_, status) = forbidden.find(word).unwrap();
+        assert_eq!(status, Status::Corrections(vec!["ALLOWLIST".into()]));
+
+        let word = typos::tokens::Word::new("allowlist", 0).unwrap();
+        assert_eq!(forbidden.find(word), None);
+    }
+
+    #[test]
+    fn test_find_without_replacement() {
+        let mut forbidden = Forbidden::new();
+        forbidden.insert("blacklist", rule(vec![]));
+
+        let word = typos::tokens::Word::new("blacklist", 0).unwrap();
+        let (_, status) = forbidden.find(word).unwrap();
+        assert_eq!(status, Status::Invalid);
+    }
+}
@@ -8,6 +8,10 @@ pub mod dict;
 #[doc(hidden)]
 pub mod file;
 #[doc(hidden)]
+pub mod forbid;
+#[doc(hidden)]
 pub mod policy;
 #[doc(hidden)]
 pub mod report;
+#[doc(hidden)]
+pub mod spans;
@@ -35,28 +35,50 @@ impl Default for ConfigStorage {
 pub struct ConfigEngine<'s> {
     storage: &'s ConfigStorage,
 
+    user: Option<crate::config::Config>,
     overrides: Option<crate::config::Config>,
     isolated: bool,
+    majority_locale: Option<crate::config::Locale>,
+
+    project_root: Option<std::path::PathBuf>,
 
     configs: std::collections::HashMap<std::path::PathBuf, DirConfig>,
+    /// Config files layered for each directory
+    derived_configs: std::collections::HashMap<std::path::PathBuf, crate::config::Config>,
     walk: Intern<crate::config::Walk>,
     tokenizer: Intern<typos::tokens::Tokenizer>,
-    dict: Intern<crate::dict::Override<'s, 's, crate::dict::BuiltIn>>,
+    dict: Intern<Box<dyn typos::Dictionary + 's>>,
+    forbidden: Intern<crate::forbid::Forbidden<'s>>,
+    severities: Intern<Severities<'s>>,
+    dict_files: std::collections::HashMap<std::path::PathBuf, Vec<(&'s str, Vec<&'s str>)>>,
 }
 
 impl<'s> ConfigEngine<'s> {
     pub fn new(storage: &'s ConfigStorage) -> Self {
         Self {
             storage,
+            user: Default::default(),
             overrides: Default::default(),
             configs: Default::default(),
+            derived_configs: Default::default(),
             isolated: false,
+            majority_locale: None,
+            project_root: None,
             walk: Default::default(),
             tokenizer: Default::default(),
             dict: Default::default(),
+            forbidden: Default::default(),
+            severities: Default::default(),
+            dict_files: Default::default(),
         }
     }
 
+    /// The user's own config, layered below project configs.
+    pub fn set_user_config(&mut self, user: Option<crate::config::Config>) -> &mut Self {
+        self.user = user;
+        self
+    }
+
     pub fn set_overrides(&mut self, overrides: crate::config::Config) -> &mut Self {
         self.overrides = Some(overrides);
         self
@@ -67,6 +89,27 @@ impl<'s> ConfigEngine<'s> {
         self
     }
 
+    /// Directory whose config files, and those in its subdirectories, are layered, with only the
+    /// closest config file at or above it being read.
+    ///
+    /// Directories already initialized need `init_dir` again.
+    pub fn set_project_root(&mut self, root: Option<std::path::PathBuf>) -> &mut Self {
+        self.project_root = root;
+        self.configs.clear();
+        self.derived_configs.clear();
+        self
+    }
+
+    /// Dialect a project mostly uses, to report spellings from the others where the `locale` is
+    /// `en`, accepting every dialect.
+    ///
+    /// Directories already initialized need `init_dir` again.
+    pub fn set_majority_locale(&mut self, locale: Option<crate::config::Locale>) -> &mut Self {
+        self.majority_locale = locale;
+        self.configs.clear();
+        self
+    }
+
     pub fn walk(&self, cwd: &std::path::Path) -> &crate::config::Walk {
         debug_assert!(cwd.is_absolute(), "{} is not absolute", cwd.display());
         let dir = self
@@ -88,13 +131,18 @@ impl<'s> ConfigEngine<'s> {
     pub fn policy(&self, path: &std::path::Path) -> Policy<'_, '_> {
         debug_assert!(path.is_absolute(), "{} is not absolute", path.display());
         let dir = self.get_dir(path).expect("`walk()` should be called first");
-        let file_config = dir.get_file_config(path);
+        let (file_config, extractor, comments) = dir.get_file_config(path);
         Policy {
             check_filenames: file_config.check_filenames,
             check_files: file_config.check_files,
             binary: file_config.binary,
+            check_doubled_words: file_config.check_doubled_words,
+            extractor,
+            comments,
             tokenizer: self.get_tokenizer(&file_config),
             dict: self.get_dict(&file_config),
+            forbidden: self.get_forbidden(&file_config),
+            severities: self.get_severities(&file_config),
         }
     }
 
@@ -107,7 +155,33 @@ impl<'s> ConfigEngine<'s> {
     }
 
     fn get_dict(&self, file: &FileConfig) -> &dyn typos::Dictionary {
-        self.dict.get(file.dict)
+        self.dict.get(file.dict).as_ref()
+    }
+
+    fn get_forbidden(&self, file: &FileConfig) -> &crate::forbid::Forbidden<'_> {
+        self.forbidden.get(file.forbidden)
+    }
+
+    fn get_severities(&self, file: &FileConfig) -> &Severities<'_> {
+        self.severities.get(file.severities)
+    }
+
+    fn get_dict_file(
+        &mut self,
+        path: &std::path::Path,
+    ) -> Result<&[(&'s str, Vec<&'s str>)], anyhow::Error> {
+        if !self.dict_files.contains_key(path) {
+            let storage = self.storage;
+            let entries = crate::dict::read_dict_file(path)?
+                .into_iter()
+                .map(|(typo, corrections)| {
+                    let corrections = corrections.iter().map(|c| storage.get(c)).collect();
+                    (storage.get(&typo), corrections)
+                })
+                .collect();
+            self.dict_files.insert(path.to_owned(), entries);
+        }
+        Ok(&self.dict_files[path])
     }
 
     fn get_dir(&self, path: &std::path::Path) -> Option<&DirConfig> {
@@ -120,19 +194,17 @@ impl<'s> ConfigEngine<'s> {
     }
 
     pub fn load_config(
-        &self,
+        &mut self,
         cwd: &std::path::Path,
     ) -> Result<crate::config::Config, anyhow::Error> {
         debug_assert!(cwd.is_absolute(), "{} is not absolute", cwd.display());
-        let mut config = crate::config::Config::default();
+        let mut config = crate::config::Config::builtin();
 
         if !self.isolated {
-            for ancestor in cwd.ancestors() {
-                if let Some(derived) = crate::config::Config::from_dir(ancestor)? {
-                    config.update(&derived);
-                    break;
-                }
+            if let Some(user) = self.user.as_ref() {
+                config.update(user);
             }
+            config.update(self.derived_config(cwd)?);
         }
         if let Some(overrides) = self.overrides.as_ref() {
             config.update(overrides);
@@ -172,6 +244,7 @@ impl<'s> ConfigEngine<'s> {
             mut default,
             type_,
             overrides,
+            ..
         } = config;
 
         let walk = self.walk.intern(files);
@@ -194,13 +267,28 @@ impl<'s> ConfigEngine<'s> {
                 }
             }
 
-            let type_config = self.init_file_config(type_engine.engine);
+            let type_config = self.init_file_config(type_engine.engine)?;
             types.insert(type_name, type_config);
         }
         default.update(&overrides);
-        let default = self.init_file_config(default);
+        let default = self.init_file_config(default)?;
 
-        type_matcher.select("all");
+        // When several types match a file, like `md`, `markdown`, and `readme` for `README.md`,
+        // the last one selected wins, so select in a fixed order rather than `select("all")`'s
+        // `HashMap` order.  Configured types take precedence, then names that sort first.
+        let mut type_names: Vec<_> = type_matcher
+            .definitions()
+            .into_iter()
+            .map(|def| def.name().to_owned())
+            .collect();
+        type_names.sort_by(|a, b| {
+            let a_configured = types.contains_key(a.as_str());
+            let b_configured = types.contains_key(b.as_str());
+            a_configured.cmp(&b_configured).then_with(|| b.cmp(a))
+        });
+        for type_name in type_names {
+            type_matcher.select(&type_name);
+        }
 
         let dir = DirConfig {
             walk,
@@ -213,10 +301,115 @@ impl<'s> ConfigEngine<'s> {
         Ok(())
     }
 
-    fn init_file_config(&mut self, engine: crate::config::EngineConfig) -> FileConfig {
+    /// Config file in `dir`, found while walking an initialized directory, if it still needs
+    /// `init_nested_dir`.
+    pub fn nested_config(
+        &self,
+        dir: &std::path::Path,
+    ) -> Result<Option<crate::config::Config>, anyhow::Error> {
+        debug_assert!(dir.is_absolute(), "{} is not absolute", dir.display());
+        if self.isolated || self.configs.contains_key(dir) {
+            return Ok(None);
+        }
+        crate::config::Config::from_dir(dir)
+    }
+
+    /// Initialize `dir` with its own config file, layered over its parent's, so `policy` reflects
+    /// it for files under `dir`.
+    pub fn init_nested_dir(
+        &mut self,
+        dir: &std::path::Path,
+        config: crate::config::Config,
+    ) -> Result<(), anyhow::Error> {
+        debug_assert!(dir.is_absolute(), "{} is not absolute", dir.display());
+        if self.isolated || self.configs.contains_key(dir) {
+            return Ok(());
+        }
+        if config.files != Default::default() {
+            log::warn!(
+                "{}: ignoring `files` fields, only read from the config of the checked directory",
+                dir.display()
+            );
+        }
+
+        let mut derived = match dir.parent() {
+            Some(parent) => self.derived_config(parent)?.clone(),
+            None => Default::default(),
+        };
+        derived.update(&config);
+        self.derived_configs.insert(dir.to_owned(), derived);
+        self.init_dir(dir)
+    }
+
+    /// Config files for `dir`, with those in it and its parents up to the project root layered
+    /// and closer ones taking precedence.  Above that, only the closest config file is read.
+    fn derived_config(
+        &mut self,
+        dir: &std::path::Path,
+    ) -> Result<&crate::config::Config, anyhow::Error> {
+        if !self.derived_configs.contains_key(dir) {
+            let parent = dir.parent().filter(|parent| self.is_in_project(parent));
+            let derived = match parent {
+                Some(parent) => {
+                    let mut derived = self.derived_config(parent)?.clone();
+                    if let Some(config) = crate::config::Config::from_dir(dir)? {
+                        derived.update(&config);
+                    }
+                    derived
+                }
+                None => dir
+                    .ancestors()
+                    .find_map(|ancestor| crate::config::Config::from_dir(ancestor).transpose())
+                    .transpose()?
+                    .unwrap_or_default(),
+            };
+            self.derived_configs.insert(dir.to_owned(), derived);
+        }
+        Ok(&self.derived_configs[dir])
+    }
+
+    /// Whether `dir`'s config files are layered with those of its subdirectories, being in the
+    /// project root or under a directory already layered.
+    fn is_in_project(&self, dir: &std::path::Path) -> bool {
+        let in_root = self
+            .project_root
+            .as_deref()
+            .map(|root| dir.starts_with(root))
+            .unwrap_or(false);
+        in_root
+            || dir
+                .ancestors()
+                .any(|ancestor| self.derived_configs.contains_key(ancestor))
+    }
+
+    fn init_file_config(
+        &mut self,
+        engine: crate::config::EngineConfig,
+    ) -> Result<FileConfig, anyhow::Error> {
         let binary = engine.binary();
         let check_filename = engine.check_filename();
         let check_file = engine.check_file();
+        let check_mode = engine.check_mode();
+        let check_doubled_words = engine.check_doubled_words();
+        let check_unknown_words = engine.check_unknown_words();
+        let severity = engine.severity();
+        let mut severities = Severities::new(severity);
+        for (word, word_severity) in engine.word_severity() {
+            severities.insert(self.storage.get(word), word_severity);
+        }
+        let mut forbidden = crate::forbid::Forbidden::new();
+        for (word, forbid) in engine.forbid() {
+            let rule = crate::forbid::Rule {
+                replacement: forbid
+                    .replacement()
+                    .iter()
+                    .map(|r| self.storage.get(r))
+                    .collect(),
+                message: forbid.message().map(|m| self.storage.get(m)),
+                severity: forbid.severity().unwrap_or(severity),
+            };
+            forbidden.insert(self.storage.get(word), rule);
+        }
         let crate::config::EngineConfig {
             tokenizer, dict, ..
         } = engine;
@@ -231,33 +424,100 @@ impl<'s> ConfigEngine<'s> {
             }
         }
 
+        let ignore_re = tokenizer_config
+            .extend_ignore_re()
+            .iter()
+            .map(|re| {
+                regex::bytes::Regex::new(re).map_err(|err| {
+                    anyhow::format_err!("Invalid `extend-ignore-re` pattern `{}`: {}", re, err)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
         let tokenizer = typos::tokens::TokenizerBuilder::new()
             .unicode(tokenizer_config.unicode())
+            .ignore_re(ignore_re)
+            .c_escapes(tokenizer_config.c_escapes())
+            .printf_placeholders(tokenizer_config.printf_placeholders())
+            .brace_placeholders(tokenizer_config.brace_placeholders())
+            .segment_words(tokenizer_config.segment_words())
             .build();
 
-        let dict = crate::dict::BuiltIn::new(dict_config.locale());
+        // Only check explicitly selected dictionaries so builds without `dict` keep working
+        for dictionary in dict_config.dictionaries.iter().flatten() {
+            if !dictionary.is_available() {
+                anyhow::bail!(
+                    "The `{}` dictionary is not available in this build, enable the `{}` feature",
+                    dictionary,
+                    dictionary.feature()
+                );
+            }
+        }
+        // The valid-word list is too large to build in by default
+        if !cfg!(feature = "words") {
+            if tokenizer_config.segment_words() {
+                anyhow::bail!(
+                    "`segment-words` needs the valid-word list, enable the `words` feature"
+                );
+            }
+            if check_unknown_words {
+                anyhow::bail!(
+                    "`check-unknown-words` needs the valid-word list, enable the `words` feature"
+                );
+            }
+        }
+        let mut dict = crate::dict::BuiltIn::new(dict_config.locale());
+        dict.dictionaries(dict_config.dictionaries())
+            .locale_strictness(dict_config.locale_strictness());
+        // A more specific `locale` already keeps spellings consistent
+        let majority_locale = self
+            .majority_locale
+            .filter(|_| dict_config.locale() == crate::config::Locale::En);
+        let dict =
+            crate::dict::Consistent::new(dict, majority_locale, dict_config.locale_strictness());
         let mut dict = crate::dict::Override::new(dict);
         dict.identifiers(
             dict_config
                 .extend_identifiers()
                 .map(|(k, v)| (self.storage.get(k), self.storage.get(v))),
         );
+        dict.ignored_identifiers(dict_config.extend_ignore_identifiers_re())?;
         dict.words(
             dict_config
                 .extend_words()
                 .map(|(k, v)| (self.storage.get(k), self.storage.get(v))),
         );
+        dict.ignored_words(dict_config.extend_ignore_words_re())?;
+        dict.words_case_sensitive(
+            dict_config
+                .extend_words_case_sensitive()
+                .map(|(k, v)| (self.storage.get(k), self.storage.get(v))),
+        );
+        for path in dict_config.extend_dict_files() {
+            let source = self.storage.get(&path.display().to_string());
+            dict.file_words(source, self.get_dict_file(path)?.iter().cloned());
+        }
 
+        let dict: Box<dyn typos::Dictionary + 's> = if check_unknown_words {
+            Box::new(crate::dict::Strict::new(dict))
+        } else {
+            Box::new(dict)
+        };
         let dict = self.dict.intern(dict);
         let tokenizer = self.tokenizer.intern(tokenizer);
+        let forbidden = self.forbidden.intern(forbidden);
+        let severities = self.severities.intern(severities);
 
-        FileConfig {
+        Ok(FileConfig {
             check_filenames: check_filename,
             check_files: check_file,
+            check_mode,
+            check_doubled_words,
             binary,
             tokenizer,
             dict,
-        }
+            forbidden,
+            severities,
+        })
     }
 }
 
@@ -298,15 +558,22 @@ struct DirConfig {
 }
 
 impl DirConfig {
-    fn get_file_config(&self, path: &std::path::Path) -> FileConfig {
+    fn get_file_config(
+        &self,
+        path: &std::path::Path,
+    ) -> (FileConfig, crate::spans::Extractor, crate::spans::Comments) {
         let match_ = self.type_matcher.matched(path, false);
         let name = match_
             .inner()
             .and_then(|g| g.file_type_def())
             .map(|f| f.name());
 
-        name.and_then(|name| self.types.get(name).copied())
-            .unwrap_or(self.default)
+        let file_config = name
+            .and_then(|name| self.types.get(name).copied())
+            .unwrap_or(self.default);
+        let extractor = crate::spans::Extractor::new(file_config.check_mode, name);
+        let comments = crate::spans::Comments::new(name);
+        (file_config, extractor, comments)
     }
 }
 
@@ -314,8 +581,12 @@ impl DirConfig {
 struct FileConfig {
     tokenizer: usize,
     dict: usize,
+    forbidden: usize,
+    severities: usize,
     check_filenames: bool,
     check_files: bool,
+    check_mode: crate::config::CheckMode,
+    check_doubled_words: bool,
     binary: bool,
 }
 
@@ -325,8 +596,14 @@ pub struct Policy<'t, 'd> {
     pub check_filenames: bool,
     pub check_files: bool,
     pub binary: bool,
+    pub check_doubled_words: bool,
+    pub extractor: crate::spans::Extractor,
+    /// Where in-file directives are recognized
+    pub comments: crate::spans::Comments,
     pub tokenizer: &'t typos::tokens::Tokenizer,
     pub dict: &'d dyn typos::Dictionary,
+    pub forbidden: &'d crate::forbid::Forbidden<'d>,
+    pub severities: &'d Severities<'d>,
 }
 
 impl<'t, 'd> Policy<'t, 'd> {
@@ -338,6 +615,10 @@ impl<'t, 'd> Policy<'t, 'd> {
 static DEFAULT_TOKENIZER: once_cell::sync::Lazy<typos::tokens::Tokenizer> =
     once_cell::sync::Lazy::new(typos::tokens::Tokenizer::new);
 static DEFAULT_DICT: crate::dict::BuiltIn = crate::dict::BuiltIn::new(crate::config::Locale::En);
+static DEFAULT_SEVERITIES: once_cell::sync::Lazy<Severities<'static>> =
+    once_cell::sync::Lazy::new(Severities::default);
+static DEFAULT_FORBIDDEN: once_cell::sync::Lazy<crate::forbid::Forbidden<'static>> =
+    once_cell::sync::Lazy::new(crate::forbid::Forbidden::new);
 
 impl<'t, 'd> Default for Policy<'t, 'd> {
     fn default() -> Self {
@@ -345,9 +626,49 @@ impl<'t, 'd> Default for Policy<'t, 'd> {
             check_filenames: true,
             check_files: true,
             binary: false,
+            check_doubled_words: false,
+            extractor: Default::default(),
+            comments: Default::default(),
             tokenizer: &DEFAULT_TOKENIZER,
             dict: &DEFAULT_DICT,
+            forbidden: &DEFAULT_FORBIDDEN,
+            severities: &DEFAULT_SEVERITIES,
+        }
+    }
+}
+
+/// How serious typos are, by word.
+#[derive(Clone, Debug, Default)]
+pub struct Severities<'w> {
+    default: crate::config::Severity,
+    words: std::collections::HashMap<
+        unicase::UniCase<&'w str>,
+        crate::config::Severity,
+        ahash::RandomState,
+    >,
+}
+
+impl<'w> Severities<'w> {
+    pub fn new(default: crate::config::Severity) -> Self {
+        Self {
+            default,
+            words: Default::default(),
+        }
+    }
+
+    pub fn insert(&mut self, word: &'w str, severity: crate::config::Severity) {
+        self.words.insert(unicase::UniCase::new(word), severity);
+    }
+
+    pub fn get(&self, typo: &str) -> crate::config::Severity {
+        // Skip hashing if we can
+        if self.words.is_empty() {
+            return self.default;
         }
+        self.words
+            .get(&unicase::UniCase::new(typo))
+            .copied()
+            .unwrap_or(self.default)
     }
 }
 
@@ -406,6 +727,123 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_load_config_builtin_types() {
+        let storage = ConfigStorage::new();
+        let mut engine = ConfigEngine::new(&storage);
+        engine.set_isolated(true);
+
+        let config = crate::config::Config {
+            type_: maplit::hashmap! {
+                kstring::KString::from_static("rust") => crate::config::TypeEngineConfig {
+                    engine: crate::config::EngineConfig {
+                        tokenizer: Some(crate::config::TokenizerConfig {
+                            c_escapes: Some(false),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            },
+            ..Default::default()
+        };
+        engine.set_overrides(config);
+
+        let cwd = std::path::Path::new(".").canonicalize().unwrap();
+        let loaded = engine.load_config(&cwd).unwrap();
+        assert!(!loaded
+            .default
+            .tokenizer
+            .clone()
+            .unwrap_or_default()
+            .c_escapes());
+        let c_escapes = |type_name: &str| {
+            loaded.type_[type_name]
+                .engine
+                .tokenizer
+                .clone()
+                .unwrap_or_default()
+                .c_escapes()
+        };
+        assert!(c_escapes("c"));
+        assert!(!c_escapes("rust"));
+    }
+
+    #[test]
+    fn test_load_config_nested_dirs() {
+        use assert_fs::prelude::*;
+
+        let temp = assert_fs::TempDir::new().unwrap();
+        temp.child("_typos.toml")
+            .write_str("[default]\nbinary = true\ncheck-file = false\n")
+            .unwrap();
+        let child = temp.child("child");
+        child
+            .child("_typos.toml")
+            .write_str("[default]\ncheck-file = true\n")
+            .unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        let child = child.path().canonicalize().unwrap();
+
+        let storage = ConfigStorage::new();
+        let mut engine = ConfigEngine::new(&storage);
+
+        // Only the closest config outside of the project
+        let loaded = engine.load_config(&child).unwrap();
+        assert_eq!(loaded.default.binary, None);
+        assert_eq!(loaded.default.check_file, Some(true));
+
+        engine.set_project_root(Some(root.clone()));
+        let loaded = engine.load_config(&root).unwrap();
+        assert_eq!(loaded.default.binary, Some(true));
+        assert_eq!(loaded.default.check_file, Some(false));
+
+        let loaded = engine.load_config(&child).unwrap();
+        assert_eq!(loaded.default.binary, Some(true));
+        assert_eq!(loaded.default.check_file, Some(true));
+    }
+
+    #[test]
+    fn test_init_nested_dir() {
+        use assert_fs::prelude::*;
+
+        let temp = assert_fs::TempDir::new().unwrap();
+        temp.child("_typos.toml")
+            .write_str(
+                "[default]
+binary = true
+check-file = false
+",
+            )
+            .unwrap();
+        let child = temp.child("child");
+        child
+            .child("_typos.toml")
+            .write_str(
+                "[default]
+check-file = true
+",
+            )
+            .unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        let child = child.path().canonicalize().unwrap();
+
+        let storage = ConfigStorage::new();
+        let mut engine = ConfigEngine::new(&storage);
+        engine.init_dir(&root).unwrap();
+        let config = engine.nested_config(&child).unwrap().unwrap();
+        engine.init_nested_dir(&child, config).unwrap();
+        assert!(engine.nested_config(&child).unwrap().is_none());
+
+        let policy = engine.policy(&root.join("a.txt"));
+        assert!(policy.binary);
+        assert!(!policy.check_files);
+        let policy = engine.policy(&child.join("a.txt"));
+        assert!(policy.binary);
+        assert!(policy.check_files);
+    }
+
     #[test]
     fn test_init_fails_on_unknown_type() {
         let storage = ConfigStorage::new();
@@ -429,6 +867,22 @@ mod test {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_policy_type_precedence() {
+        let storage = ConfigStorage::new();
+        let mut engine = ConfigEngine::new(&storage);
+        engine.set_isolated(true);
+
+        let config =
+            crate::config::Config::from_toml("[default]\ncheck-mode = \"prose\"\n").unwrap();
+        engine.set_overrides(config);
+
+        let cwd = std::path::Path::new(".").canonicalize().unwrap();
+        engine.init_dir(&cwd).unwrap();
+        let policy = engine.policy(&cwd.join("README.md"));
+        assert_eq!(policy.extractor, crate::spans::Extractor::Markdown);
+    }
+
     #[test]
     fn test_policy_default() {
         let storage = ConfigStorage::new();
@@ -476,6 +930,29 @@ mod test {
         assert!(policy.binary);
     }
 
+    #[test]
+    fn test_init_fails_on_invalid_ignore_re() {
+        let storage = ConfigStorage::new();
+        let mut engine = ConfigEngine::new(&storage);
+        engine.set_isolated(true);
+
+        let config = crate::config::Config {
+            default: crate::config::EngineConfig {
+                tokenizer: Some(crate::config::TokenizerConfig {
+                    extend_ignore_re: vec!["(".to_owned()],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        engine.set_overrides(config);
+
+        let cwd = std::path::Path::new(".").canonicalize().unwrap();
+        let err = engine.init_dir(&cwd).unwrap_err();
+        assert!(err.to_string().contains("pattern `(`"), "{}", err);
+    }
+
     #[test]
     fn test_policy_type_specific() {
         let storage = ConfigStorage::new();
@@ -509,4 +986,48 @@ mod test {
         let policy = engine.policy(&cwd.join(NEVER_EXIST_TYPE));
         assert!(!policy.binary);
     }
+
+    #[test]
+    fn test_policy_severities() {
+        let storage = ConfigStorage::new();
+        let mut engine = ConfigEngine::new(&storage);
+        engine.set_isolated(true);
+
+        let type_name = kstring::KString::from_static(NEVER_EXIST_TYPE);
+
+        let config = crate::config::Config {
+            default: crate::config::EngineConfig {
+                word_severity: maplit::hashmap! {
+                    "teh".into() => crate::config::Severity::Info,
+                },
+                ..Default::default()
+            },
+            type_: maplit::hashmap! {
+                type_name.clone() => crate::config::TypeEngineConfig {
+                    extend_glob: vec![type_name],
+                    engine: crate::config::EngineConfig {
+                        severity: Some(crate::config::Severity::Warning),
+                        ..Default::default()
+                    },
+                },
+            },
+            ..Default::default()
+        };
+        engine.set_overrides(config);
+
+        let cwd = std::path::Path::new(".").canonicalize().unwrap();
+        engine.init_dir(&cwd).unwrap();
+        let policy = engine.policy(&cwd.join("Cargo.toml"));
+        assert_eq!(
+            policy.severities.get("recieve"),
+            crate::config::Severity::Error
+        );
+        assert_eq!(policy.severities.get("Teh"), crate::config::Severity::Info);
+        let policy = engine.policy(&cwd.join(NEVER_EXIST_TYPE));
+        assert_eq!(
+            policy.severities.get("recieve"),
+            crate::config::Severity::Warning
+        );
+        assert_eq!(policy.severities.get("teh"), crate::config::Severity::Info);
+    }
 }
@@ -13,6 +13,7 @@ pub trait Report: Send + Sync {
 pub enum Message<'m> {
     BinaryFile(BinaryFile<'m>),
     Typo(Typo<'m>),
+    Forbidden(Forbidden<'m>),
     File(File<'m>),
     Parse(Parse<'m>),
     Error(Error<'m>),
@@ -22,7 +23,8 @@ impl<'m> Message<'m> {
     pub fn is_correction(&self) -> bool {
         match self {
             Message::BinaryFile(_) => false,
-            Message::Typo(c) => c.corrections.is_correction(),
+            Message::Typo(c) => c.corrections.is_correction() || c.corrections.is_suggestion(),
+            Message::Forbidden(_) => true,
             Message::File(_) => false,
             Message::Parse(_) => false,
             Message::Error(_) => false,
@@ -33,18 +35,32 @@ impl<'m> Message<'m> {
         match self {
             Message::BinaryFile(_) => false,
             Message::Typo(_) => false,
+            Message::Forbidden(_) => false,
             Message::File(_) => false,
             Message::Parse(_) => false,
             Message::Error(_) => true,
         }
     }
 
+    /// How serious a finding is, for messages that are findings.
+    pub fn severity(&self) -> Option<crate::config::Severity> {
+        match self {
+            Message::Typo(c) => Some(c.severity),
+            Message::Forbidden(f) => Some(f.severity),
+            _ => None,
+        }
+    }
+
     pub fn context(self, context: Option<Context<'m>>) -> Self {
         match self {
             Message::Typo(typo) => {
                 let typo = typo.context(context);
                 Message::Typo(typo)
             }
+            Message::Forbidden(forbidden) => {
+                let forbidden = forbidden.context(context);
+                Message::Forbidden(forbidden)
+            }
             Message::Parse(parse) => {
                 let parse = parse.context(context);
                 Message::Parse(parse)
@@ -75,6 +91,10 @@ pub struct Typo<'m> {
     pub byte_offset: usize,
     pub typo: &'m str,
     pub corrections: typos::Status<'m>,
+    /// Details for each of the corrections, in the same order, if known.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub details: Vec<typos::CorrectionDetails<'m>>,
+    pub severity: crate::config::Severity,
 }
 
 impl<'m> Default for Typo<'m> {
@@ -85,6 +105,40 @@ impl<'m> Default for Typo<'m> {
             byte_offset: 0,
             typo: "",
             corrections: typos::Status::Invalid,
+            details: Vec::new(),
+            severity: crate::config::Severity::Error,
+        }
+    }
+}
+
+/// A word forbidden by the config, whatever its spelling.
+#[derive(Clone, Debug, serde::Serialize, derive_setters::Setters)]
+#[non_exhaustive]
+pub struct Forbidden<'m> {
+    #[serde(flatten)]
+    pub context: Option<Context<'m>>,
+    #[serde(skip)]
+    pub buffer: Cow<'m, [u8]>,
+    pub byte_offset: usize,
+    pub word: &'m str,
+    /// What to use instead, if anything.
+    pub replacement: Vec<Cow<'m, str>>,
+    /// Why the word is forbidden.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<&'m str>,
+    pub severity: crate::config::Severity,
+}
+
+impl<'m> Default for Forbidden<'m> {
+    fn default() -> Self {
+        Self {
+            context: None,
+            buffer: Cow::Borrowed(&[]),
+            byte_offset: 0,
+            word: "",
+            replacement: Vec::new(),
+            message: None,
+            severity: crate::config::Severity::Error,
         }
     }
 }
@@ -0,0 +1,912 @@
+use bstr::ByteSlice;
+
+/// Select the regions of a file to spell check.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Extractor {
+    /// Check the entire file.
+    #[default]
+    All,
+    /// Only check comments and string literals.
+    Code(&'static Syntax),
+    /// Only check Markdown prose, skipping code and link destinations.
+    Markdown,
+    /// Only check HTML / XML text and human-readable attribute values.
+    Html,
+}
+
+impl Extractor {
+    /// Choose how to extract spans for a file of `type_name` (see `ignore::types`).
+    ///
+    /// When a file type isn't recognized, all of it is checked.
+    pub fn new(mode: crate::config::CheckMode, type_name: Option<&str>) -> Self {
+        match mode {
+            crate::config::CheckMode::All => Extractor::All,
+            crate::config::CheckMode::Prose => match type_name {
+                Some("markdown") | Some("md") => Extractor::Markdown,
+                Some("html") | Some("xml") => Extractor::Html,
+                _ => type_name
+                    .and_then(Syntax::from_type)
+                    .map(Extractor::Code)
+                    .unwrap_or(Extractor::All),
+            },
+        }
+    }
+
+    /// Byte ranges of `buffer` to check, in order and non-overlapping.
+    pub fn extract(&self, buffer: &[u8]) -> Vec<std::ops::Range<usize>> {
+        match self {
+            Extractor::All => {
+                let all = 0..buffer.len();
+                vec![all]
+            }
+            Extractor::Code(syntax) => syntax.extract(buffer),
+            Extractor::Markdown => markdown::extract(buffer),
+            Extractor::Html => html::extract(buffer),
+        }
+    }
+}
+
+/// Find the comments of a file, where in-file directives are recognized.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Comments {
+    /// The syntax isn't known, so treat the entire file as a comment.
+    #[default]
+    All,
+    Code(&'static Syntax),
+    /// `<!-- -->` comments in Markdown, HTML, or XML.
+    Markup,
+}
+
+impl Comments {
+    /// Choose how to find comments for a file of `type_name` (see `ignore::types`).
+    pub fn new(type_name: Option<&str>) -> Self {
+        match type_name {
+            Some("markdown") | Some("md") | Some("html") | Some("xml") => Comments::Markup,
+            _ => type_name
+                .and_then(Syntax::from_type)
+                .map(Comments::Code)
+                .unwrap_or(Comments::All),
+        }
+    }
+
+    /// Byte ranges of `buffer` in comments, in order and non-overlapping.
+    pub fn extract(&self, buffer: &[u8]) -> Vec<std::ops::Range<usize>> {
+        match self {
+            Comments::All => {
+                let all = 0..buffer.len();
+                vec![all]
+            }
+            Comments::Code(syntax) => syntax.lex(buffer, false),
+            Comments::Markup => html::comments(buffer),
+        }
+    }
+}
+
+/// Enough of a language's lexical structure to find its comments and string literals.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Syntax {
+    line_comments: &'static [&'static str],
+    block_comments: &'static [Delimited],
+    strings: &'static [Delimited],
+    /// Skip `'c'` so a quote in a character literal isn't mistaken for the start of a string
+    char_literals: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Delimited {
+    open: &'static str,
+    close: &'static str,
+    escapes: bool,
+    multiline: bool,
+}
+
+impl Delimited {
+    const fn new(open: &'static str, close: &'static str) -> Self {
+        Self {
+            open,
+            close,
+            escapes: false,
+            multiline: true,
+        }
+    }
+
+    const fn escapes(mut self) -> Self {
+        self.escapes = true;
+        self
+    }
+
+    const fn single_line(mut self) -> Self {
+        self.multiline = false;
+        self
+    }
+
+    /// Find where the content starting at `start` ends, returning the end of the content and
+    /// where to resume lexing.
+    fn find_close(&self, buffer: &[u8], start: usize) -> (usize, usize) {
+        let close = self.close.as_bytes();
+        let mut i = start;
+        while i < buffer.len() {
+            let rest = &buffer[i..];
+            if rest.starts_with(close) {
+                return (i, i + close.len());
+            } else if self.escapes && rest[0] == b'\\' {
+                i += 2;
+            } else if !self.multiline && rest[0] == b'\n' {
+                return (i, i);
+            } else {
+                i += 1;
+            }
+        }
+        (buffer.len(), buffer.len())
+    }
+}
+
+const SLASH_SLASH: &[&str] = &["//"];
+const HASH: &[&str] = &["#"];
+const DASH_DASH: &[&str] = &["--"];
+
+const SLASH_STAR: &[Delimited] = &[Delimited::new("/*", "*/")];
+
+const DOUBLE_QUOTE: Delimited = Delimited::new("\"", "\"").escapes().single_line();
+const SINGLE_QUOTE: Delimited = Delimited::new("'", "'").escapes().single_line();
+
+const C_LIKE: Syntax = Syntax {
+    line_comments: SLASH_SLASH,
+    block_comments: SLASH_STAR,
+    strings: &[DOUBLE_QUOTE],
+    char_literals: true,
+};
+
+const TRIPLE_QUOTED: Syntax = Syntax {
+    line_comments: SLASH_SLASH,
+    block_comments: SLASH_STAR,
+    strings: &[Delimited::new("\"\"\"", "\"\"\""), DOUBLE_QUOTE],
+    char_literals: true,
+};
+
+const CSS: Syntax = Syntax {
+    line_comments: &[],
+    block_comments: SLASH_STAR,
+    strings: &[DOUBLE_QUOTE, SINGLE_QUOTE],
+    char_literals: false,
+};
+
+const DART: Syntax = Syntax {
+    line_comments: SLASH_SLASH,
+    block_comments: SLASH_STAR,
+    strings: &[
+        Delimited::new("\"\"\"", "\"\"\"").escapes(),
+        Delimited::new("'''", "'''").escapes(),
+        DOUBLE_QUOTE,
+        SINGLE_QUOTE,
+    ],
+    char_literals: false,
+};
+
+const GO: Syntax = Syntax {
+    line_comments: SLASH_SLASH,
+    block_comments: SLASH_STAR,
+    strings: &[DOUBLE_QUOTE, Delimited::new("`", "`")],
+    char_literals: true,
+};
+
+const HASKELL: Syntax = Syntax {
+    line_comments: DASH_DASH,
+    block_comments: &[Delimited::new("{-", "-}")],
+    strings: &[DOUBLE_QUOTE],
+    char_literals: true,
+};
+
+const JS: Syntax = Syntax {
+    line_comments: SLASH_SLASH,
+    block_comments: SLASH_STAR,
+    strings: &[
+        DOUBLE_QUOTE,
+        SINGLE_QUOTE,
+        Delimited::new("`", "`").escapes(),
+    ],
+    char_literals: false,
+};
+
+const LISP: Syntax = Syntax {
+    line_comments: &[";"],
+    block_comments: &[],
+    strings: &[Delimited::new("\"", "\"").escapes()],
+    char_literals: false,
+};
+
+const LUA: Syntax = Syntax {
+    line_comments: DASH_DASH,
+    block_comments: &[Delimited::new("--[[", "]]")],
+    strings: &[DOUBLE_QUOTE, SINGLE_QUOTE, Delimited::new("[[", "]]")],
+    char_literals: false,
+};
+
+const PHP: Syntax = Syntax {
+    line_comments: &["//", "#"],
+    block_comments: SLASH_STAR,
+    strings: &[
+        Delimited::new("\"", "\"").escapes(),
+        Delimited::new("'", "'").escapes(),
+    ],
+    char_literals: false,
+};
+
+const PYTHON: Syntax = Syntax {
+    line_comments: HASH,
+    block_comments: &[],
+    strings: &[
+        Delimited::new("\"\"\"", "\"\"\"").escapes(),
+        Delimited::new("'''", "'''").escapes(),
+        DOUBLE_QUOTE,
+        SINGLE_QUOTE,
+    ],
+    char_literals: false,
+};
+
+const RUST: Syntax = Syntax {
+    line_comments: SLASH_SLASH,
+    block_comments: SLASH_STAR,
+    strings: &[
+        Delimited::new("r##\"", "\"##"),
+        Delimited::new("r#\"", "\"#"),
+        Delimited::new("r\"", "\""),
+        Delimited::new("\"", "\"").escapes(),
+    ],
+    char_literals: true,
+};
+
+const SCRIPT: Syntax = Syntax {
+    line_comments: HASH,
+    block_comments: &[],
+    strings: &[
+        Delimited::new("\"", "\"").escapes(),
+        Delimited::new("'", "'"),
+    ],
+    char_literals: false,
+};
+
+const SQL: Syntax = Syntax {
+    line_comments: DASH_DASH,
+    block_comments: SLASH_STAR,
+    strings: &[Delimited::new("'", "'")],
+    char_literals: false,
+};
+
+impl Syntax {
+    /// Look up the syntax for an `ignore::types` file type name.
+    pub fn from_type(type_name: &str) -> Option<&'static Self> {
+        let syntax = match type_name {
+            "c" | "cpp" | "cs" | "csharp" | "h" | "java" | "objc" | "objcpp" => &C_LIKE,
+            "kotlin" | "scala" | "swift" => &TRIPLE_QUOTED,
+            "css" | "less" => &CSS,
+            "dart" => &DART,
+            "go" => &GO,
+            "haskell" => &HASKELL,
+            "js" | "ts" => &JS,
+            "clojure" | "elisp" | "lisp" => &LISP,
+            "lua" => &LUA,
+            "php" => &PHP,
+            "py" => &PYTHON,
+            "rust" => &RUST,
+            "cmake" | "make" | "perl" | "ruby" | "sh" => &SCRIPT,
+            "sql" => &SQL,
+            _ => return None,
+        };
+        Some(syntax)
+    }
+
+    fn extract(&self, buffer: &[u8]) -> Vec<std::ops::Range<usize>> {
+        self.lex(buffer, true)
+    }
+
+    /// Find comments, and string literals when `with_strings`.
+    fn lex(&self, buffer: &[u8], with_strings: bool) -> Vec<std::ops::Range<usize>> {
+        let mut spans = Vec::new();
+        let mut i = 0;
+        while i < buffer.len() {
+            let rest = &buffer[i..];
+            if let Some(delimited) = find_open(self.block_comments, rest) {
+                let start = i + delimited.open.len();
+                let (end, next) = delimited.find_close(buffer, start);
+                spans.push(start..end);
+                i = next;
+            } else if let Some(open) = self
+                .line_comments
+                .iter()
+                .find(|open| rest.starts_with(open.as_bytes()))
+            {
+                let start = i + open.len();
+                let end = buffer[start..]
+                    .find_byte(b'\n')
+                    .map(|end| start + end)
+                    .unwrap_or_else(|| buffer.len());
+                spans.push(start..end);
+                i = end;
+            } else if let Some(delimited) = find_open(self.strings, rest) {
+                let start = i + delimited.open.len();
+                let (end, next) = delimited.find_close(buffer, start);
+                if with_strings {
+                    spans.push(start..end);
+                }
+                i = next;
+            } else if self.char_literals && rest[0] == b'\'' {
+                i += char_literal_len(rest).unwrap_or(1);
+            } else {
+                i += 1;
+            }
+        }
+        spans.retain(|span| !span.is_empty());
+        spans
+    }
+}
+
+fn find_open(delimiters: &'static [Delimited], rest: &[u8]) -> Option<&'static Delimited> {
+    delimiters
+        .iter()
+        .find(|delimited| rest.starts_with(delimited.open.as_bytes()))
+}
+
+/// Length of the character literal at the start of `rest`, if it is one (rather than a lifetime
+/// or an apostrophe).
+fn char_literal_len(rest: &[u8]) -> Option<usize> {
+    // Long enough for `'\u{10FFFF}'`
+    const MAX_ESCAPE_LEN: usize = 12;
+
+    debug_assert_eq!(rest[0], b'\'');
+    let content = &rest[1..];
+    if content.starts_with(b"\\") {
+        let len = content
+            .iter()
+            .take(MAX_ESCAPE_LEN)
+            .skip(2)
+            .position(|b| *b == b'\'')?;
+        Some(len + 4)
+    } else {
+        let (c, c_len) = bstr::decode_utf8(content);
+        c?;
+        (content.get(c_len) == Some(&b'\'')).then(|| c_len + 2)
+    }
+}
+
+/// The ranges of `buffer` in between the sorted `skipped` ranges.
+fn unskipped(
+    skipped: impl IntoIterator<Item = std::ops::Range<usize>>,
+    len: usize,
+) -> Vec<std::ops::Range<usize>> {
+    let mut spans = Vec::new();
+    let mut start = 0;
+    for skip in skipped {
+        if start < skip.start {
+            spans.push(start..skip.start);
+        }
+        start = start.max(skip.end);
+    }
+    if start < len {
+        spans.push(start..len);
+    }
+    spans
+}
+
+mod markdown {
+    use bstr::ByteSlice;
+
+    /// Skip fenced code blocks, code spans, link destinations, autolinks, and link reference
+    /// definitions.
+    ///
+    /// This is line-based, so it doesn't support constructs that span lines, like code spans
+    /// with a line break in them.  Indented code blocks are checked since they can't be told
+    /// apart from list continuations without a full parse.
+    pub(crate) fn extract(buffer: &[u8]) -> Vec<std::ops::Range<usize>> {
+        let mut skipped = Vec::new();
+        let mut fence: Option<(u8, usize, usize)> = None;
+        let mut line_start = 0;
+        for line in buffer.lines_with_terminator() {
+            let line_end = line_start + line.len();
+            let content = line.trim_end_with(|c| c == '\n' || c == '\r');
+            let (indent, unindented) = split_indent(content);
+
+            if let Some((fence_char, fence_len, fence_start)) = fence {
+                let close_len = unindented.iter().take_while(|b| **b == fence_char).count();
+                if indent <= 3
+                    && fence_len <= close_len
+                    && unindented[close_len..].trim().is_empty()
+                {
+                    skipped.push(fence_start..line_end);
+                    fence = None;
+                }
+            } else if let Some((fence_char, fence_len)) = open_fence(indent, unindented) {
+                fence = Some((fence_char, fence_len, line_start));
+            } else if indent <= 3 && is_reference_definition(unindented) {
+                skipped.push(line_start..line_end);
+            } else {
+                skip_inline(content, line_start, &mut skipped);
+            }
+
+            line_start = line_end;
+        }
+        if let Some((_, _, fence_start)) = fence {
+            skipped.push(fence_start..buffer.len());
+        }
+
+        super::unskipped(skipped, buffer.len())
+    }
+
+    fn split_indent(line: &[u8]) -> (usize, &[u8]) {
+        let indent = line.iter().take_while(|b| **b == b' ').count();
+        (indent, &line[indent..])
+    }
+
+    fn open_fence(indent: usize, line: &[u8]) -> Option<(u8, usize)> {
+        if 3 < indent {
+            return None;
+        }
+        let fence_char = *line.first()?;
+        if fence_char != b'`' && fence_char != b'~' {
+            return None;
+        }
+        let fence_len = line.iter().take_while(|b| **b == fence_char).count();
+        if fence_len < 3 {
+            return None;
+        }
+        // Otherwise, this is a code span
+        if fence_char == b'`' && line[fence_len..].contains(&b'`') {
+            return None;
+        }
+        Some((fence_char, fence_len))
+    }
+
+    /// `[label]: destination "title"`
+    fn is_reference_definition(line: &[u8]) -> bool {
+        if !line.starts_with(b"[") || line.starts_with(b"[^") {
+            return false;
+        }
+        line.find(b"]:")
+            .map(|end| 1 < end && !line[1..end].contains(&b']'))
+            .unwrap_or(false)
+    }
+
+    fn skip_inline(line: &[u8], line_start: usize, skipped: &mut Vec<std::ops::Range<usize>>) {
+        let mut i = 0;
+        while i < line.len() {
+            let rest = &line[i..];
+            match rest[0] {
+                b'\\' => {
+                    i += 2;
+                }
+                b'`' => {
+                    let tick_len = rest.iter().take_while(|b| **b == b'`').count();
+                    if let Some(close) = find_tick_run(&rest[tick_len..], tick_len) {
+                        let end = i + tick_len + close + tick_len;
+                        skipped.push(line_start + i..line_start + end);
+                        i = end;
+                    } else {
+                        i += tick_len;
+                    }
+                }
+                b']' if rest.get(1) == Some(&b'(') => {
+                    // Keep the `]` in case it ends a word
+                    let start = i + 1;
+                    let end = match_paren(&line[start..])
+                        .map(|len| start + len)
+                        .unwrap_or_else(|| line.len());
+                    skipped.push(line_start + start..line_start + end);
+                    i = end;
+                }
+                b'<' if is_autolink(rest) => {
+                    let end = rest.find_byte(b'>').map(|end| i + end + 1).unwrap_or(i + 1);
+                    skipped.push(line_start + i..line_start + end);
+                    i = end;
+                }
+                _ => {
+                    i += 1;
+                }
+            }
+        }
+    }
+
+    /// Offset of the next run of exactly `tick_len` backticks.
+    fn find_tick_run(rest: &[u8], tick_len: usize) -> Option<usize> {
+        let mut i = 0;
+        while i < rest.len() {
+            if rest[i] == b'`' {
+                let run = rest[i..].iter().take_while(|b| **b == b'`').count();
+                if run == tick_len {
+                    return Some(i);
+                }
+                i += run;
+            } else {
+                i += 1;
+            }
+        }
+        None
+    }
+
+    /// Length of the parenthesized group at the start of `rest`, including the parentheses.
+    fn match_paren(rest: &[u8]) -> Option<usize> {
+        debug_assert_eq!(rest[0], b'(');
+        let mut depth = 0;
+        for (i, b) in rest.iter().enumerate() {
+            match b {
+                b'(' => depth += 1,
+                b')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(i + 1);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// `<https://example.com>` or `<mailto:me@example.com>`
+    fn is_autolink(rest: &[u8]) -> bool {
+        let end = match rest.find_byte(b'>') {
+            Some(end) => end,
+            None => return false,
+        };
+        let link = &rest[1..end];
+        let scheme_len = link
+            .iter()
+            .take_while(|b| b.is_ascii_alphanumeric() || **b == b'+' || **b == b'.' || **b == b'-')
+            .count();
+        2 <= scheme_len
+            && link.get(scheme_len) == Some(&b':')
+            && !link.iter().any(|b| b.is_ascii_whitespace())
+    }
+}
+
+mod html {
+    use bstr::ByteSlice;
+
+    /// Attributes whose values are meant to be read by people
+    const PROSE_ATTRIBUTES: &[&[u8]] = &[b"alt", b"placeholder", b"title"];
+    const COMMENT: (&[u8], &[u8]) = (b"<!--", b"-->");
+    /// Elements whose content isn't markup or text
+    const RAW_TEXT_ELEMENTS: &[&[u8]] = &[b"script", b"style"];
+
+    /// Check text and `PROSE_ATTRIBUTES`, skipping tags, comments, entity references, and
+    /// `RAW_TEXT_ELEMENTS`.
+    pub(crate) fn extract(buffer: &[u8]) -> Vec<std::ops::Range<usize>> {
+        let mut spans = Vec::new();
+        let mut text_start = 0;
+        while let Some(tag_start) = buffer[text_start..].find_byte(b'<').map(|i| text_start + i) {
+            push_text(&mut spans, buffer, text_start..tag_start);
+            text_start = skip_markup(buffer, tag_start, &mut spans);
+        }
+        push_text(&mut spans, buffer, text_start..buffer.len());
+        spans
+    }
+
+    /// The contents of `<!-- -->` comments.
+    pub(crate) fn comments(buffer: &[u8]) -> Vec<std::ops::Range<usize>> {
+        let mut spans = Vec::new();
+        let mut i = 0;
+        while let Some(open) = buffer[i..].find(COMMENT.0) {
+            let start = i + open + COMMENT.0.len();
+            let (end, next) = find_close(buffer, start, COMMENT.1);
+            spans.push(start..end);
+            i = next;
+        }
+        spans
+    }
+
+    /// Returns the offset after the markup starting at `start`.
+    fn skip_markup(buffer: &[u8], start: usize, spans: &mut Vec<std::ops::Range<usize>>) -> usize {
+        const CDATA: (&[u8], &[u8]) = (b"<![CDATA[", b"]]>");
+
+        let rest = &buffer[start..];
+        if rest.starts_with(COMMENT.0) {
+            let (_, next) = find_close(buffer, start + COMMENT.0.len(), COMMENT.1);
+            return next;
+        }
+        if rest.starts_with(CDATA.0) {
+            let content_start = start + CDATA.0.len();
+            let (end, next) = find_close(buffer, content_start, CDATA.1);
+            push_text(spans, buffer, content_start..end);
+            return next;
+        }
+        if rest.starts_with(b"<!") || rest.starts_with(b"<?") || rest.starts_with(b"</") {
+            let (_, next) = find_close(buffer, start, b">");
+            return next;
+        }
+
+        let name_len = rest[1..].iter().take_while(|b| is_name_char(**b)).count();
+        if name_len == 0 {
+            // A stray `<` in text
+            return start + 1;
+        }
+        let name = &rest[1..1 + name_len];
+
+        let mut i = start + 1 + name_len;
+        let mut self_closing = false;
+        loop {
+            i = skip_whitespace(buffer, i);
+            let rest = &buffer[i..];
+            if rest.is_empty() {
+                return i;
+            } else if rest.starts_with(b">") {
+                i += 1;
+                break;
+            } else if rest.starts_with(b"/>") {
+                i += 2;
+                self_closing = true;
+                break;
+            }
+
+            let attr_len = rest.iter().take_while(|b| is_name_char(**b)).count().max(1);
+            let attr = &rest[0..attr_len];
+            i = skip_whitespace(buffer, i + attr_len);
+            if buffer.get(i) != Some(&b'=') {
+                continue;
+            }
+            i = skip_whitespace(buffer, i + 1);
+            let (value, next) = match buffer.get(i) {
+                Some(quote @ b'"') | Some(quote @ b'\'') => {
+                    let (end, next) = find_close(buffer, i + 1, &[*quote]);
+                    (i + 1..end, next)
+                }
+                _ => {
+                    let len = buffer[i..]
+                        .iter()
+                        .take_while(|b| !b.is_ascii_whitespace() && **b != b'>')
+                        .count();
+                    (i..i + len, i + len)
+                }
+            };
+            if PROSE_ATTRIBUTES
+                .iter()
+                .any(|prose| attr.eq_ignore_ascii_case(prose))
+            {
+                push_text(spans, buffer, value);
+            }
+            i = next;
+        }
+
+        if !self_closing
+            && RAW_TEXT_ELEMENTS
+                .iter()
+                .any(|raw| name.eq_ignore_ascii_case(raw))
+        {
+            i = buffer[i..]
+                .find_iter(b"</")
+                .map(|end| i + end)
+                .find(|end| {
+                    let close_name = &buffer[end + 2..];
+                    name.len() <= close_name.len()
+                        && close_name[0..name.len()].eq_ignore_ascii_case(name)
+                })
+                .unwrap_or(buffer.len());
+        }
+
+        i
+    }
+
+    /// Record text, skipping entity references like `&nbsp;` and `&#x27;`.
+    fn push_text(
+        spans: &mut Vec<std::ops::Range<usize>>,
+        buffer: &[u8],
+        text: std::ops::Range<usize>,
+    ) {
+        let mut start = text.start;
+        let mut i = text.start;
+        while let Some(amp) = buffer[i..text.end].find_byte(b'&').map(|amp| i + amp) {
+            match entity_len(&buffer[amp..text.end]) {
+                Some(len) => {
+                    if start < amp {
+                        spans.push(start..amp);
+                    }
+                    i = amp + len;
+                    start = i;
+                }
+                None => {
+                    i = amp + 1;
+                }
+            }
+        }
+        if start < text.end {
+            spans.push(start..text.end);
+        }
+    }
+
+    fn entity_len(rest: &[u8]) -> Option<usize> {
+        let name_len = rest[1..]
+            .iter()
+            .take_while(|b| b.is_ascii_alphanumeric() || **b == b'#')
+            .count();
+        (0 < name_len && rest.get(1 + name_len) == Some(&b';')).then(|| name_len + 2)
+    }
+
+    fn find_close(buffer: &[u8], start: usize, close: &[u8]) -> (usize, usize) {
+        match buffer[start..].find(close) {
+            Some(end) => (start + end, start + end + close.len()),
+            None => (buffer.len(), buffer.len()),
+        }
+    }
+
+    fn skip_whitespace(buffer: &[u8], start: usize) -> usize {
+        start
+            + buffer[start..]
+                .iter()
+                .take_while(|b| b.is_ascii_whitespace())
+                .count()
+    }
+
+    fn is_name_char(b: u8) -> bool {
+        b.is_ascii_alphanumeric() || b == b'-' || b == b'_' || b == b':' || b == b'.'
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn extract<'b>(type_name: &str, buffer: &'b str) -> Vec<&'b str> {
+        Extractor::new(crate::config::CheckMode::Prose, Some(type_name))
+            .extract(buffer.as_bytes())
+            .into_iter()
+            .map(|span| &buffer[span])
+            .collect()
+    }
+
+    #[test]
+    fn test_all() {
+        let buffer = "fn main() {}";
+        let actual = Extractor::new(crate::config::CheckMode::All, Some("rust"));
+        let all = 0..buffer.len();
+        assert_eq!(actual.extract(buffer.as_bytes()), vec![all]);
+    }
+
+    #[test]
+    fn test_unknown_type_is_all() {
+        let buffer = "key = value";
+        let actual = Extractor::new(crate::config::CheckMode::Prose, Some("toml"));
+        assert_eq!(actual, Extractor::All);
+        let actual = Extractor::new(crate::config::CheckMode::Prose, None);
+        let all = 0..buffer.len();
+        assert_eq!(actual.extract(buffer.as_bytes()), vec![all]);
+    }
+
+    #[test]
+    fn test_c_like() {
+        let buffer = r#"
+/* Block
+ * comment */
+int mian(int argc) { // Line comment
+    char quote = '"';
+    printf("Hello \"world\"\n", 'x');
+}
+"#;
+        let actual = extract("c", buffer);
+        assert_eq!(
+            actual,
+            vec![
+                " Block\n * comment ",
+                " Line comment",
+                r#"Hello \"world\"\n"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rust() {
+        let buffer = r###"
+/// Doc comment
+fn mian<'a>(s: &'a str) -> char {
+    let raw = r#"Raw "quoted""#;
+    let escaped = "Multi
+line";
+    '\''
+}
+"###;
+        let actual = extract("rust", buffer);
+        assert_eq!(
+            actual,
+            vec!["/ Doc comment", r#"Raw "quoted""#, "Multi\nline"]
+        );
+    }
+
+    #[test]
+    fn test_python() {
+        let buffer = r#"
+def mian():
+    """Docstring with "quotes"."""
+    return 'don\'t' # Comment
+"#;
+        let actual = extract("py", buffer);
+        assert_eq!(
+            actual,
+            vec![r#"Docstring with "quotes"."#, r"don\'t", " Comment"]
+        );
+    }
+
+    #[test]
+    fn test_unterminated() {
+        let buffer = "let s = \"Unterminated\nmian";
+        let actual = extract("js", buffer);
+        assert_eq!(actual, vec!["Unterminated"]);
+
+        let buffer = "/* Unterminated\nmian";
+        let actual = extract("js", buffer);
+        assert_eq!(actual, vec![" Unterminated\nmian"]);
+    }
+
+    #[test]
+    fn test_markdown() {
+        let buffer = r#"# Titel
+
+Some `inline cdoe` and a [lnk](https://example.com/pth "Tilte") in prose.
+See <https://example.com/autolnk> or \`escaped\`.
+
+```rust
+fn mian() {}
+```
+
+~~~~
+````
+nott closed
+~~~~
+
+[refrence]: https://example.com/refrence
+"#;
+        let actual = extract("markdown", buffer);
+        assert_eq!(
+            actual,
+            vec![
+                "# Titel\n\nSome ",
+                " and a [lnk]",
+                " in prose.\nSee ",
+                " or \\`escaped\\`.\n\n",
+                "\n",
+                "\n",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_markdown_unclosed_fence() {
+        let buffer = "Prose\n```\nmian\n";
+        let actual = extract("md", buffer);
+        assert_eq!(actual, vec!["Prose\n"]);
+    }
+
+    #[test]
+    fn test_html() {
+        let buffer = r#"<!DOCTYPE html>
+<!-- Commment -->
+<div class="btn-primry" title='Helo'>&nbsp;wrold&#x27;s <img alt=Pictur src="a.png"/></div>
+<SCRIPT>var mian = 1;</SCRIPT><style>.clss {}</style>
+<![CDATA[Dta]]> a < b
+"#;
+        let actual = extract("html", buffer);
+        assert_eq!(
+            actual,
+            vec!["\n", "\n", "Helo", "wrold", "s ", "Pictur", "\n", "\n", "Dta", " a ", " b\n"]
+        );
+    }
+
+    fn comments<'b>(type_name: Option<&str>, buffer: &'b str) -> Vec<&'b str> {
+        Comments::new(type_name)
+            .extract(buffer.as_bytes())
+            .into_iter()
+            .map(|span| &buffer[span])
+            .collect()
+    }
+
+    #[test]
+    fn test_comments() {
+        let buffer = "let s = \"// typos: off\"; // typos: ignore-line\n/* block */";
+        let actual = comments(Some("rust"), buffer);
+        assert_eq!(actual, vec![" typos: ignore-line", " block "]);
+
+        let buffer = "`typos: off`\n<!-- typos: off -->\n";
+        let actual = comments(Some("md"), buffer);
+        assert_eq!(actual, vec![" typos: off "]);
+
+        let buffer = "typos: off";
+        let actual = comments(Some("toml"), buffer);
+        assert_eq!(actual, vec![buffer]);
+    }
+}
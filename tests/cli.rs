@@ -1,9 +1,16 @@
 use assert_cmd::Command;
 
+/// `typos`, ignoring the user config of whoever runs the tests
+fn typos() -> Command {
+    let mut cmd = Command::cargo_bin("typos").unwrap();
+    cmd.env("TYPOS_USER_CONFIG", "");
+    cmd
+}
+
 #[test]
 #[cfg(feature = "dict")]
 fn test_stdin_success() {
-    let mut cmd = Command::cargo_bin("typos").unwrap();
+    let mut cmd = typos();
     cmd.arg("-").write_stdin("Hello world");
     cmd.assert().success();
 }
@@ -11,7 +18,7 @@ fn test_stdin_success() {
 #[test]
 #[cfg(feature = "dict")]
 fn test_stdin_failure() {
-    let mut cmd = Command::cargo_bin("typos").unwrap();
+    let mut cmd = typos();
     cmd.arg("-").write_stdin("Apropriate world");
     cmd.assert().code(2);
 }
@@ -19,7 +26,7 @@ fn test_stdin_failure() {
 #[test]
 #[cfg(feature = "dict")]
 fn test_stdin_correct() {
-    let mut cmd = Command::cargo_bin("typos").unwrap();
+    let mut cmd = typos();
     cmd.arg("-")
         .arg("--write-changes")
         .write_stdin("Apropriate world");
@@ -29,7 +36,7 @@ fn test_stdin_correct() {
 #[test]
 #[cfg(feature = "dict")]
 fn test_file_failure() {
-    let mut cmd = Command::cargo_bin("typos").unwrap();
+    let mut cmd = typos();
     cmd.arg("README.md");
     cmd.assert().code(2);
 }
@@ -37,7 +44,7 @@ fn test_file_failure() {
 #[test]
 #[cfg(feature = "dict")]
 fn test_relative_dir_failure() {
-    let mut cmd = Command::cargo_bin("typos").unwrap();
+    let mut cmd = typos();
     cmd.arg(".");
     cmd.assert().code(2);
 }
@@ -45,6 +52,733 @@ fn test_relative_dir_failure() {
 #[test]
 #[cfg(feature = "dict")]
 fn test_assumed_dir_failure() {
-    let mut cmd = Command::cargo_bin("typos").unwrap();
+    let mut cmd = typos();
     cmd.assert().code(2);
 }
+
+#[test]
+#[cfg(feature = "dict")]
+fn test_stdin_suppressed() {
+    let mut cmd = typos();
+    cmd.arg("-")
+        .write_stdin("// typos: ignore-next-line\nApropriate world\n");
+    cmd.assert().success();
+}
+
+#[test]
+#[cfg(feature = "dict")]
+fn test_stdin_correct_suppressed() {
+    let mut cmd = typos();
+    cmd.arg("-")
+        .arg("--write-changes")
+        .write_stdin("Apropriate # typos: ignore-line\nApropriate world\n");
+    cmd.assert()
+        .success()
+        .stdout("Apropriate # typos: ignore-line\nAppropriate world\n");
+}
+
+#[test]
+#[cfg(feature = "dict")]
+fn test_file_correct_prose() {
+    use assert_fs::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    let file = temp.child("main.rs");
+    file.write_str("fn apropriate() {}\n// Apropriate \"teh\"\n")
+        .unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .arg("--isolated")
+        .arg("--check-mode=prose")
+        .arg("--write-changes")
+        .arg("main.rs");
+    cmd.assert().success();
+    file.assert("fn apropriate() {}\n// Appropriate \"the\"\n");
+
+    temp.close().unwrap();
+}
+
+#[test]
+#[cfg(feature = "dict")]
+fn test_file_correct_markdown_prose() {
+    use assert_fs::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    let file = temp.child("README.md");
+    file.write_str("Apropriate `apropriate`\n\n```\napropriate\n```\nApropriate\n")
+        .unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .arg("--isolated")
+        .arg("--check-mode=prose")
+        .arg("--write-changes")
+        .arg("README.md");
+    cmd.assert().success();
+    file.assert("Appropriate `apropriate`\n\n```\napropriate\n```\nAppropriate\n");
+
+    temp.close().unwrap();
+}
+
+#[test]
+#[cfg(feature = "dict")]
+fn test_file_correct_case_sensitive() {
+    use assert_fs::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("typos.toml")
+        .write_str("[default.extend-words-case-sensitive]\nGithub = \"GitHub\"\n")
+        .unwrap();
+    let file = temp.child("notes.txt");
+    file.write_str("Github github GITHUB GitHub\n").unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .arg("--write-changes")
+        .arg("notes.txt");
+    cmd.assert().success();
+    file.assert("GitHub github GITHUB GitHub\n");
+
+    temp.close().unwrap();
+}
+
+#[test]
+#[cfg(feature = "dict")]
+fn test_file_correct_doubled_words() {
+    use assert_fs::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    let file = temp.child("notes.txt");
+    file.write_str("Read the the\nthe manual\n").unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path()).arg("notes.txt");
+    cmd.assert().success();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .arg("--check-doubled-words")
+        .arg("--write-changes")
+        .arg("notes.txt");
+    cmd.assert().success();
+    file.assert("Read the manual\n");
+
+    temp.close().unwrap();
+}
+
+#[test]
+#[cfg(all(feature = "dict", feature = "words"))]
+fn test_file_unknown_words() {
+    use assert_fs::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    let file = temp.child("notes.txt");
+    file.write_str("compleetly\n").unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path()).arg("notes.txt");
+    cmd.assert().success();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .arg("--check-unknown-words")
+        .arg("--format=brief")
+        .arg("notes.txt");
+    cmd.assert().code(2).stdout(predicates::str::contains(
+        "`compleetly` is unknown, did you mean `completely`",
+    ));
+
+    temp.close().unwrap();
+}
+
+#[test]
+#[cfg(not(feature = "words"))]
+fn test_file_unknown_words_unavailable() {
+    let mut cmd = typos();
+    cmd.arg("--check-unknown-words").arg("README.md");
+    cmd.assert().code(78).stderr(predicates::str::contains(
+        "`check-unknown-words` needs the valid-word list, enable the `words` feature",
+    ));
+}
+
+#[test]
+#[cfg(feature = "dict")]
+fn test_config_extends() {
+    use assert_fs::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("shared/base.toml")
+        .write_str("[default.extend-words]\nteh = \"teh\"\nrecieve = \"recieve\"\n")
+        .unwrap();
+    temp.child("repo/_typos.toml")
+        .write_str(
+            "extends = [\"../shared/base.toml\"]\n[default.extend-words]\nrecieve = \"receive\"\n",
+        )
+        .unwrap();
+    temp.child("repo/a.txt").write_str("teh recieve\n").unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .arg("--format=brief")
+        .arg("repo/a.txt");
+    cmd.assert()
+        .code(2)
+        .stdout("repo/a.txt:1:4: `recieve` -> `receive`\n");
+
+    temp.child("shared/base.toml")
+        .write_str("extends = [\"loop.toml\"]\n")
+        .unwrap();
+    temp.child("shared/loop.toml")
+        .write_str("extends = [\"base.toml\"]\n")
+        .unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path()).arg("repo");
+    cmd.assert().code(78).stderr(predicates::str::contains(
+        "Config files extend each other in a cycle",
+    ));
+
+    temp.close().unwrap();
+}
+
+#[test]
+#[cfg(feature = "dict")]
+fn test_nested_config() {
+    use assert_fs::prelude::*;
+    use predicates::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("_typos.toml")
+        .write_str("[default.extend-words]\nteh = \"teh\"\n")
+        .unwrap();
+    temp.child("sub/_typos.toml")
+        .write_str("[default.extend-words]\nrecieve = \"recieve\"\n")
+        .unwrap();
+    temp.child("a.txt").write_str("teh recieve\n").unwrap();
+    temp.child("sub/deeper/b.txt")
+        .write_str("teh recieve\n")
+        .unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path()).arg("--format=brief");
+    cmd.assert()
+        .code(2)
+        .stdout(predicates::str::contains(
+            "a.txt:1:4: `recieve` -> `receive`",
+        ))
+        .stdout(predicates::str::contains("b.txt").not())
+        .stdout(predicates::str::contains("`teh`").not());
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path()).arg("sub/deeper/b.txt");
+    cmd.assert().success();
+
+    temp.close().unwrap();
+}
+
+#[test]
+#[cfg(feature = "dict")]
+fn test_nested_config_relative_paths() {
+    use assert_fs::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("_typos.toml")
+        .write_str("[default.extend-words]\nteh = \"teh\"\n")
+        .unwrap();
+    temp.child("sub/_typos.toml")
+        .write_str("[default.extend-words]\nrecieve = \"recieve\"\n")
+        .unwrap();
+    temp.child("a.txt").write_str("teh recieve\n").unwrap();
+    temp.child("sub/deeper/b.txt")
+        .write_str("teh recieve\n")
+        .unwrap();
+
+    for path in ["./sub", "sub/../sub/deeper", "./sub/deeper/b.txt"] {
+        let mut cmd = typos();
+        cmd.current_dir(temp.path()).arg(path);
+        cmd.assert().success();
+    }
+
+    // Above the current directory, only the closest config is read
+    let mut cmd = typos();
+    cmd.current_dir(temp.path().join("sub"))
+        .arg("--format=brief")
+        .arg("deeper");
+    cmd.assert()
+        .code(2)
+        .stdout("deeper/b.txt:1:0: `teh` -> `the`\n");
+
+    temp.close().unwrap();
+}
+
+#[test]
+#[cfg(all(unix, feature = "dict"))]
+fn test_nested_config_symlinked_paths() {
+    use assert_fs::prelude::*;
+    use predicates::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("project/_typos.toml")
+        .write_str("[default.extend-words]\nteh = \"teh\"\n")
+        .unwrap();
+    temp.child("project/sub/_typos.toml")
+        .write_str("[default.extend-words]\nrecieve = \"recieve\"\n")
+        .unwrap();
+    temp.child("project/a.txt")
+        .write_str("teh recieve\n")
+        .unwrap();
+    temp.child("project/sub/b.txt")
+        .write_str("teh recieve\n")
+        .unwrap();
+    std::os::unix::fs::symlink(temp.path().join("project"), temp.path().join("link")).unwrap();
+    std::os::unix::fs::symlink("../a.txt", temp.path().join("project/sub/c.txt")).unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .arg("--format=brief")
+        .arg("link");
+    cmd.assert()
+        .code(2)
+        .stdout(predicates::str::contains(
+            "a.txt:1:4: `recieve` -> `receive`",
+        ))
+        .stdout(predicates::str::contains("b.txt").not());
+
+    // A symlinked file is checked with the config of where it was found
+    let mut cmd = typos();
+    cmd.current_dir(temp.path()).arg("link/sub/c.txt");
+    cmd.assert().success();
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn test_nested_config_files_ignored() {
+    use assert_fs::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("sub/_typos.toml")
+        .write_str("[files]\nextend-exclude = [\"b.txt\"]\n")
+        .unwrap();
+    temp.child("sub/b.txt").write_str("Hello\n").unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path()).arg("-v");
+    cmd.assert()
+        .success()
+        .stderr(predicates::str::contains("ignoring `files` fields"));
+
+    temp.close().unwrap();
+}
+
+#[test]
+#[cfg(feature = "dict")]
+fn test_manifest_config() {
+    use assert_fs::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("rust/Cargo.toml")
+        .write_str(
+            "[package]\nname = \"foo\"\n\n[package.metadata.typos.default.extend-words]\nteh = \"teh\"\n",
+        )
+        .unwrap();
+    temp.child("rust/a.txt").write_str("teh\n").unwrap();
+    temp.child("python/pyproject.toml")
+        .write_str("[tool.typos.default.extend-words]\nteh = \"teh\"\n")
+        .unwrap();
+    temp.child("python/a.txt").write_str("teh\n").unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path());
+    cmd.assert().success();
+
+    temp.child("rust/_typos.toml")
+        .write_str("[default]\ncheck-file = true\n")
+        .unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .arg("--format=brief")
+        .arg("rust/a.txt");
+    cmd.assert()
+        .code(2)
+        .stdout("rust/a.txt:1:0: `teh` -> `the`\n");
+
+    temp.close().unwrap();
+}
+
+#[test]
+#[cfg(feature = "dict")]
+fn test_user_config() {
+    use assert_fs::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("home/typos/config.toml")
+        .write_str("[default.extend-words]\nteh = \"teh\"\nrecieve = \"recieve\"\n")
+        .unwrap();
+    temp.child("repo/_typos.toml")
+        .write_str("[default.extend-words]\nrecieve = \"receive\"\n")
+        .unwrap();
+    temp.child("repo/a.txt").write_str("teh recieve\n").unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .env_remove("TYPOS_USER_CONFIG")
+        .env("XDG_CONFIG_HOME", temp.child("home").path())
+        .arg("--format=brief")
+        .arg("repo/a.txt");
+    cmd.assert()
+        .code(2)
+        .stdout("repo/a.txt:1:4: `recieve` -> `receive`\n");
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .env(
+            "TYPOS_USER_CONFIG",
+            temp.child("home/typos/config.toml").path(),
+        )
+        .arg("--dump-config=-");
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains("teh = 'teh'"));
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .env_remove("TYPOS_USER_CONFIG")
+        .env("XDG_CONFIG_HOME", temp.child("home").path())
+        .arg("--isolated")
+        .arg("--format=brief")
+        .arg("repo/a.txt");
+    cmd.assert()
+        .code(2)
+        .stdout(predicates::str::contains("`teh` -> `the`"));
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .env("XDG_CONFIG_HOME", temp.child("home").path())
+        .arg("--format=brief")
+        .arg("repo/a.txt");
+    cmd.assert()
+        .code(2)
+        .stdout(predicates::str::contains("`teh` -> `the`"));
+
+    temp.close().unwrap();
+}
+
+#[test]
+#[cfg(feature = "dict")]
+fn test_severity() {
+    use assert_fs::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("_typos.toml")
+        .write_str(
+            r#"
+[default]
+severity = "warning"
+
+[default.word-severity]
+teh = "error"
+"#,
+        )
+        .unwrap();
+    temp.child("notes.txt").write_str("recieve\n").unwrap();
+    temp.child("lib.rs").write_str("// teh\n").unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path()).arg("notes.txt");
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains("warning: `recieve` should be"));
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .arg("--format=json")
+        .arg("notes.txt");
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains(r#""severity":"warning""#));
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .arg("--format=brief")
+        .arg("lib.rs");
+    cmd.assert()
+        .code(2)
+        .stdout(predicates::str::contains("lib.rs:1:3: `teh` -> `the`"));
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn test_forbid() {
+    use assert_fs::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("_typos.toml")
+        .write_str(
+            r#"
+[default.forbid]
+whitelist = { replacement = "allowlist", message = "Prefer inclusive language" }
+master = { replacement = ["main", "primary"], severity = "warning" }
+
+[type.rust.forbid.master]
+replacement = "main"
+"#,
+        )
+        .unwrap();
+    let notes = temp.child("notes.txt");
+    notes.write_str("The master branch\n").unwrap();
+    let code = temp.child("lib.rs");
+    code.write_str("// Whitelist the master branch\n").unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .arg("--format=brief")
+        .arg("notes.txt");
+    cmd.assert().success().stdout(predicates::str::contains(
+        "`master` is forbidden, use `main`, `primary`",
+    ));
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .arg("--format=brief")
+        .arg("lib.rs");
+    cmd.assert().code(2).stdout(predicates::str::contains(
+        "`Whitelist` is forbidden, use `Allowlist`: Prefer inclusive language",
+    ));
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .arg("--write-changes")
+        .arg("notes.txt")
+        .arg("lib.rs");
+    cmd.assert().success();
+    notes.assert("The master branch\n");
+    code.assert("// Allowlist the main branch\n");
+
+    temp.close().unwrap();
+}
+
+#[test]
+#[cfg(feature = "dict")]
+fn test_forbid_in_identifiers() {
+    use assert_fs::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("_typos.toml")
+        .write_str("[default.forbid]\nwhitelist = { replacement = \"allowlist\" }\n")
+        .unwrap();
+    let file = temp.child("notes.txt");
+    file.write_str("whitelist teh\nwhitelistEntries teh\n")
+        .unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .arg("--format=brief")
+        .arg("notes.txt");
+    cmd.assert().code(2).stdout(
+        "notes.txt:1:0: `whitelist` is forbidden, use `allowlist`
+notes.txt:1:10: `teh` -> `the`
+notes.txt:2:0: `whitelist` is forbidden, use `allowlist`
+notes.txt:2:17: `teh` -> `the`
+",
+    );
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .arg("--write-changes")
+        .arg("--format=brief")
+        .arg("notes.txt");
+    cmd.assert()
+        .code(2)
+        .stdout("notes.txt:2:0: `whitelist` is forbidden, use `allowlist`\n");
+    file.assert("allowlist the\nwhitelistEntries the\n");
+
+    temp.close().unwrap();
+}
+
+#[test]
+#[cfg(feature = "vars")]
+fn test_variant_consistency() {
+    use assert_fs::prelude::*;
+    use predicates::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("a.txt").write_str("color\ncolors\n").unwrap();
+    temp.child("b.txt").write_str("colour\nteh\n").unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .arg("--variant-consistency")
+        .arg("--format=brief");
+    cmd.assert()
+        .code(2)
+        .stdout(predicates::str::contains("`colour` -> `color`"))
+        .stdout(predicates::str::contains("`teh` -> `the`"))
+        .stdout(predicates::str::contains("a.txt").not());
+
+    // Hidden, so it isn't tallied
+    temp.child(".typos.toml")
+        .write_str("[default.extend-words]\ncolour = \"colour\"\n")
+        .unwrap();
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .arg("--variant-consistency")
+        .arg("--format=brief");
+    cmd.assert()
+        .code(2)
+        .stdout(predicates::str::contains("colour").not());
+
+    temp.close().unwrap();
+}
+
+#[test]
+#[cfg(feature = "vars")]
+fn test_variant_consistency_en_locale() {
+    use assert_fs::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("_typos.toml")
+        .write_str("[default]\nlocale = \"en\"\n")
+        .unwrap();
+    let file = temp.child("a.txt");
+    file.write_str("colour colour colour color\n").unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .arg("--variant-consistency")
+        .arg("--format=brief");
+    cmd.assert()
+        .code(2)
+        .stdout("./a.txt:1:21: `color` -> `colour`\n");
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .arg("--variant-consistency")
+        .arg("--write-changes");
+    cmd.assert().success();
+    file.assert("colour colour colour colour\n");
+
+    temp.close().unwrap();
+}
+
+#[test]
+#[cfg(all(feature = "dict", feature = "words"))]
+fn test_file_correct_segment_words() {
+    use assert_fs::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("typos.toml")
+        .write_str("[default]\nsegment-words = true\n")
+        .unwrap();
+    let file = temp.child("notes.txt");
+    file.write_str("recievedata\n").unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .arg("--write-changes")
+        .arg("notes.txt");
+    cmd.assert().success();
+    file.assert("receivedata\n");
+
+    temp.close().unwrap();
+}
+
+#[test]
+#[cfg(feature = "dict")]
+fn test_file_correct_dict_files() {
+    use assert_fs::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("typos.toml")
+        .write_str("[default]\nextend-dict-files = [\"dicts/team.txt\", \"dicts/team.csv\"]\n")
+        .unwrap();
+    temp.child("dicts/team.txt")
+        .write_str("# Team typos\nwidgit->widget\n")
+        .unwrap();
+    temp.child("dicts/team.csv")
+        .write_str("gizmoe,gizmo\n")
+        .unwrap();
+    let file = temp.child("notes.txt");
+    file.write_str("Widgit gizmoe\n").unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .arg("--write-changes")
+        .arg("notes.txt");
+    cmd.assert().success();
+    file.assert("Widget gizmo\n");
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn test_dict_files_parse_error() {
+    use assert_fs::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("typos.toml")
+        .write_str("[default]\nextend-dict-files = [\"team.txt\"]\n")
+        .unwrap();
+    temp.child("team.txt")
+        .write_str("widgit->widget\ngizmoe\n")
+        .unwrap();
+    temp.child("notes.txt").write_str("Hello\n").unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path()).arg("notes.txt");
+    let assert = cmd.assert().code(78);
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+    assert!(
+        stderr.contains("team.txt:2: expected `typo->correction`, got `gizmoe`"),
+        "{}",
+        stderr
+    );
+
+    temp.close().unwrap();
+}
+
+#[test]
+#[cfg(feature = "codespell")]
+fn test_file_dictionaries_source() {
+    use assert_fs::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("typos.toml")
+        .write_str("[default]\ndictionaries = [\"typos\", \"codespell\"]\n")
+        .unwrap();
+    temp.child("notes.txt").write_str("abailable\n").unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path())
+        .arg("--format")
+        .arg("json")
+        .arg("notes.txt");
+    cmd.assert().code(2).stdout(predicates::str::contains(
+        r#""corrections":["available"],"details":[{"source":"codespell""#,
+    ));
+
+    temp.close().unwrap();
+}
+
+#[test]
+#[cfg(not(feature = "wikipedia"))]
+fn test_file_dictionaries_unavailable() {
+    use assert_fs::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    temp.child("typos.toml")
+        .write_str("[default]\ndictionaries = [\"wikipedia\"]\n")
+        .unwrap();
+    temp.child("notes.txt").write_str("Hello\n").unwrap();
+
+    let mut cmd = typos();
+    cmd.current_dir(temp.path()).arg("notes.txt");
+    cmd.assert().code(78).stderr(predicates::str::contains(
+        "The `wikipedia` dictionary is not available in this build",
+    ));
+
+    temp.close().unwrap();
+}